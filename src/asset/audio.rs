@@ -91,6 +91,19 @@ impl Audio {
         self.playing_src = None
     }
 
+    /// 流れているBGMを, 再開できるように一時停止する.
+    pub fn pause_bgm(&self) {
+        if let Some(src) = &self.playing_src {
+            src.pause();
+        }
+    }
+
+    pub fn resume_bgm(&self) {
+        if let Some(src) = &self.playing_src {
+            src.resume();
+        }
+    }
+
     pub fn play_se(&self, ctx: &mut Context, se: Se) -> GameResult {
         let src = self
             .se_data_map
@@ -30,6 +30,7 @@ impl Color {
             MinoBlock::ORANGE => graphics::Color::from_rgb(255, 148, 64),
             MinoBlock::GREEN => graphics::Color::from_rgb(32, 184, 32),
             MinoBlock::RED => graphics::Color::from_rgb(184, 32, 32),
+            MinoBlock::GRAY => graphics::Color::from_rgb(128, 128, 128),
         }
     }
 }
@@ -105,6 +105,11 @@ impl Image {
                     ALPHA_VALUE => 255u8,
                     _ => unreachable!(),
                 },
+                MinoBlock::GRAY => match idx % 4 {
+                    RED_VALUE | GREEN_VALUE | BLUE_VALUE => v.saturating_sub(32),
+                    ALPHA_VALUE => 255u8,
+                    _ => unreachable!(),
+                },
             })
             .collect::<Vec<_>>();
 
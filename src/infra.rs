@@ -1,4 +1,14 @@
+pub mod net {
+    pub mod tcp_peer;
+}
+
 pub mod repo {
     pub mod default_asset_provider;
     pub mod default_control_code_repository;
+    pub mod file_puzzle_repository;
+    pub mod file_record_repository;
+    pub mod file_retry_settings_repository;
+    pub mod file_sandbox_repository;
+    pub mod file_session_repository;
+    pub mod versus_control_code_repository;
 }
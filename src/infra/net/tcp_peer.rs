@@ -0,0 +1,168 @@
+use std::{
+    io,
+    io::{ErrorKind, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    time::Duration,
+};
+
+use crate::model::net_message::NetMessage;
+
+pub const DEFAULT_PORT: u16 = 23040;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+const READ_BUFFER_SIZE: usize = 4096;
+
+/// # TCPで繋がった対戦相手
+///
+/// ゲームの更新を止めないように, 送受信はノンブロッキングで行う.
+/// 書ききれなかったメッセージは溜めておき, 次に送受信するときに続きを送る.
+pub struct TcpPeer {
+    stream: TcpStream,
+    incoming: Vec<u8>,
+    outgoing: Vec<u8>,
+}
+
+impl TcpPeer {
+    pub fn new(stream: TcpStream) -> io::Result<TcpPeer> {
+        stream.set_nonblocking(true)?;
+        stream.set_nodelay(true)?;
+
+        Ok(TcpPeer {
+            stream,
+            incoming: Vec::new(),
+            outgoing: Vec::new(),
+        })
+    }
+
+    pub fn connect(addr: &SocketAddr) -> io::Result<TcpPeer> {
+        TcpPeer::new(TcpStream::connect_timeout(addr, CONNECT_TIMEOUT)?)
+    }
+
+    /// 待ち受けている接続があれば受け入れる. なければ `None` を返す.
+    pub fn accept(listener: &TcpListener) -> io::Result<Option<TcpPeer>> {
+        match listener.accept() {
+            Ok((stream, _)) => TcpPeer::new(stream).map(Some),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn send(&mut self, message: &NetMessage) -> io::Result<()> {
+        serde_json::to_writer(&mut self.outgoing, message)?;
+        self.outgoing.push(b'\n');
+
+        self.flush()
+    }
+
+    /// 今までに届いたメッセージを全て取り出す. 相手が切断していたときはエラーを返す.
+    pub fn receive(&mut self) -> io::Result<Vec<NetMessage>> {
+        self.flush()?;
+
+        let mut buf = [0u8; READ_BUFFER_SIZE];
+        loop {
+            match self.stream.read(&mut buf) {
+                Ok(0) => return Err(ErrorKind::ConnectionAborted.into()),
+                Ok(n) => self.incoming.extend_from_slice(&buf[..n]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        let mut messages = Vec::new();
+        while let Some(pos) = self.incoming.iter().position(|&b| b == b'\n') {
+            let line = self.incoming.drain(..=pos).collect::<Vec<_>>();
+            let message = serde_json::from_slice(&line[..line.len() - 1])
+                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+            messages.push(message);
+        }
+
+        Ok(messages)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        while !self.outgoing.is_empty() {
+            match self.stream.write(&self.outgoing) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(n) => {
+                    self.outgoing.drain(..n);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 対戦相手を待ち受けるためのソケットを開く.
+pub fn listen(port: u16) -> io::Result<TcpListener> {
+    let listener = TcpListener::bind(("0.0.0.0", port))?;
+    listener.set_nonblocking(true)?;
+
+    Ok(listener)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::Ipv4Addr, thread, time::Instant};
+
+    use super::*;
+
+    fn connected_pair() -> (TcpPeer, TcpPeer) {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let joined = TcpPeer::connect(&addr).unwrap();
+        let hosted = wait_for(|| TcpPeer::accept(&listener).unwrap());
+
+        (hosted, joined)
+    }
+
+    fn wait_for<T>(mut f: impl FnMut() -> Option<T>) -> T {
+        let started = Instant::now();
+        while started.elapsed() < Duration::from_secs(5) {
+            if let Some(t) = f() {
+                return t;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        panic!("timed out");
+    }
+
+    #[test]
+    fn test_exchange_messages_over_localhost() {
+        let (mut host, mut join) = connected_pair();
+
+        host.send(&NetMessage::Hello { seed: 42 }).unwrap();
+        host.send(&NetMessage::Garbage { lines: 4 }).unwrap();
+
+        let mut received = Vec::new();
+        let received = wait_for(|| {
+            received.extend(join.receive().unwrap());
+            Some(received.clone()).filter(|r| r.len() == 2)
+        });
+
+        assert_eq!(
+            received,
+            vec![
+                NetMessage::Hello { seed: 42 },
+                NetMessage::Garbage { lines: 4 }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detects_disconnection() {
+        let (host, mut join) = connected_pair();
+        drop(host);
+
+        let result = wait_for(|| join.receive().err());
+
+        assert_eq!(result.kind(), ErrorKind::ConnectionAborted);
+    }
+}
@@ -25,6 +25,9 @@ impl ControlCodeRepository for DefaultControlCodeRepository {
             RotateCounterclockwise => vec![J],
             RotateClockwise => vec![K],
             SwapHold => vec![Space],
+            ToggleStats => vec![Tab],
+            ControlCode::Pause => vec![Escape],
+            Retry => vec![R],
             MenuUp => up,
             MenuDown => down,
             MenuRight => right,
@@ -46,6 +49,9 @@ impl ControlCodeRepository for DefaultControlCodeRepository {
             RotateCounterclockwise => vec![South],
             RotateClockwise => vec![East],
             SwapHold => vec![LeftTrigger],
+            ToggleStats => vec![North],
+            Pause => vec![Start],
+            Retry => vec![West],
             MenuUp => vec![DPadUp],
             MenuDown => vec![DPadDown],
             MenuRight => vec![DPadRight],
@@ -55,3 +61,65 @@ impl ControlCodeRepository for DefaultControlCodeRepository {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use test_case::test_case;
+
+    use super::*;
+    use crate::model::control_code::ControlCode::*;
+
+    const PLAYING: [ControlCode; 7] = [
+        MoveLeft,
+        MoveRight,
+        SoftDrop,
+        HardDrop,
+        RotateCounterclockwise,
+        RotateClockwise,
+        SwapHold,
+    ];
+
+    /// 同じ場面で同時に読まれる操作同士は, キーもボタンも共有しない.
+    #[test_case(&[&PLAYING[..], &[ToggleStats, Pause, Retry]] ; "play")]
+    #[test_case(&[&PLAYING[..], &[MenuBack, Retry]] ; "puzzle")]
+    #[test_case(&[&PLAYING[..], &[MenuBack]] ; "sandbox")]
+    // ポーズメニューでは ポーズ と 戻る のどちらでも再開するので, 同じキーを共有してよい
+    #[test_case(&[&[MenuUp, MenuDown, MenuEnter, MenuBack, Retry]] ; "pause")]
+    #[test_case(&[&[MenuEnter, MenuBack, Retry]] ; "results")]
+    #[test_case(&[&[MenuUp, MenuDown, MenuRight, MenuLeft, MenuEnter, MenuBack]] ; "menu")]
+    fn test_controls_in_same_scene_do_not_share_inputs(groups: &[&[ControlCode]]) {
+        let repo = DefaultControlCodeRepository;
+        let codes = groups.concat();
+
+        for (a, b) in codes.iter().tuple_combinations() {
+            let keys = repo.key_codes(b);
+            let shared_keys = repo
+                .key_codes(a)
+                .into_iter()
+                .filter(|key| keys.contains(key))
+                .collect_vec();
+            assert!(
+                shared_keys.is_empty(),
+                "{:?} and {:?} share {:?}",
+                a,
+                b,
+                shared_keys
+            );
+
+            let buttons = repo.buttons(b);
+            let shared_buttons = repo
+                .buttons(a)
+                .into_iter()
+                .filter(|button| buttons.contains(button))
+                .collect_vec();
+            assert!(
+                shared_buttons.is_empty(),
+                "{:?} and {:?} share {:?}",
+                a,
+                b,
+                shared_buttons
+            );
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::BTreeSet;
+
+use ggez::{filesystem, Context, GameError, GameResult};
+
+use crate::{model::repo::puzzle_repository::PuzzleRepository, tetris::puzzle::PuzzlePack};
+
+const PACKS_DIR: &str = "/puzzles";
+
+/// リソースディレクトリのJSONファイルからパックを読み込み,
+/// 解いたパズルはユーザーディレクトリにプレイヤーごとに保存する.
+pub struct FilePuzzleRepository;
+
+impl FilePuzzleRepository {
+    fn solved_path(player: &str) -> String {
+        format!("/puzzles-solved-{}.json", player)
+    }
+}
+
+impl PuzzleRepository for FilePuzzleRepository {
+    fn packs(&self, ctx: &mut Context) -> GameResult<Vec<PuzzlePack>> {
+        if !filesystem::is_dir(ctx, PACKS_DIR) {
+            return Ok(Vec::new());
+        }
+
+        let mut paths = filesystem::read_dir(ctx, PACKS_DIR)?
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let file = filesystem::open(ctx, &path)?;
+                let pack: PuzzlePack = serde_json::from_reader(file)
+                    .map_err(|e| GameError::ResourceLoadError(e.to_string()))?;
+                pack.validate().map_err(GameError::ResourceLoadError)?;
+
+                Ok(pack)
+            })
+            .collect()
+    }
+
+    fn solved(&self, ctx: &mut Context, player: &str) -> GameResult<BTreeSet<String>> {
+        let path = FilePuzzleRepository::solved_path(player);
+        if !filesystem::exists(ctx, &path) {
+            return Ok(BTreeSet::new());
+        }
+
+        let file = filesystem::open(ctx, &path)?;
+        serde_json::from_reader(file).map_err(|e| GameError::ResourceLoadError(e.to_string()))
+    }
+
+    fn mark_solved(&self, ctx: &mut Context, player: &str, key: &str) -> GameResult {
+        let mut solved = self.solved(ctx, player)?;
+        solved.insert(key.to_owned());
+
+        let file = filesystem::create(ctx, FilePuzzleRepository::solved_path(player))?;
+        serde_json::to_writer(file, &solved).map_err(|e| GameError::FilesystemError(e.to_string()))
+    }
+}
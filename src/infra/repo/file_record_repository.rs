@@ -0,0 +1,37 @@
+use ggez::{filesystem, Context, GameError, GameResult};
+
+use crate::model::{play_record::PlayRecord, repo::record_repository::RecordRepository};
+
+const RECORDS_PATH: &str = "/records.json";
+
+/// ユーザーディレクトリのJSONファイルに記録を保存する.
+pub struct FileRecordRepository;
+
+impl FileRecordRepository {
+    fn load_all(&self, ctx: &mut Context) -> GameResult<Vec<PlayRecord>> {
+        if !filesystem::exists(ctx, RECORDS_PATH) {
+            return Ok(Vec::new());
+        }
+
+        let file = filesystem::open(ctx, RECORDS_PATH)?;
+        serde_json::from_reader(file).map_err(|e| GameError::ResourceLoadError(e.to_string()))
+    }
+}
+
+impl RecordRepository for FileRecordRepository {
+    fn save(&self, ctx: &mut Context, record: PlayRecord) -> GameResult {
+        let mut records = self.load_all(ctx)?;
+        records.push(record);
+
+        let file = filesystem::create(ctx, RECORDS_PATH)?;
+        serde_json::to_writer(file, &records).map_err(|e| GameError::FilesystemError(e.to_string()))
+    }
+
+    fn records(&self, ctx: &mut Context, mode: &str) -> GameResult<Vec<PlayRecord>> {
+        Ok(self
+            .load_all(ctx)?
+            .into_iter()
+            .filter(|record| record.mode == mode)
+            .collect())
+    }
+}
@@ -0,0 +1,27 @@
+use ggez::{filesystem, Context, GameError, GameResult};
+
+use crate::model::{
+    repo::retry_settings_repository::RetrySettingsRepository,
+    retry_settings::RetrySettings,
+};
+
+const RETRY_SETTINGS_PATH: &str = "/retry.json";
+
+/// ユーザーディレクトリに, やり直しの設定をJSONファイルとして保存する.
+pub struct FileRetrySettingsRepository;
+
+impl RetrySettingsRepository for FileRetrySettingsRepository {
+    fn save(&self, ctx: &mut Context, settings: &RetrySettings) -> GameResult {
+        let file = filesystem::create(ctx, RETRY_SETTINGS_PATH)?;
+        serde_json::to_writer(file, settings).map_err(|e| GameError::FilesystemError(e.to_string()))
+    }
+
+    fn load(&self, ctx: &mut Context) -> GameResult<RetrySettings> {
+        if !filesystem::exists(ctx, RETRY_SETTINGS_PATH) {
+            return Ok(RetrySettings::default());
+        }
+
+        let file = filesystem::open(ctx, RETRY_SETTINGS_PATH)?;
+        serde_json::from_reader(file).map_err(|e| GameError::ResourceLoadError(e.to_string()))
+    }
+}
@@ -0,0 +1,26 @@
+use ggez::{filesystem, Context, GameError, GameResult};
+
+use crate::model::{repo::sandbox_repository::SandboxRepository, sandbox_setup::SandboxSetup};
+
+const SANDBOX_PATH: &str = "/sandbox.json";
+
+/// ユーザーディレクトリに, エディタで作った盤面を1つだけJSONファイルとして保存する.
+pub struct FileSandboxRepository;
+
+impl SandboxRepository for FileSandboxRepository {
+    fn save(&self, ctx: &mut Context, setup: &SandboxSetup) -> GameResult {
+        let file = filesystem::create(ctx, SANDBOX_PATH)?;
+        serde_json::to_writer(file, setup).map_err(|e| GameError::FilesystemError(e.to_string()))
+    }
+
+    fn load(&self, ctx: &mut Context) -> GameResult<Option<SandboxSetup>> {
+        if !filesystem::exists(ctx, SANDBOX_PATH) {
+            return Ok(None);
+        }
+
+        let file = filesystem::open(ctx, SANDBOX_PATH)?;
+        serde_json::from_reader(file)
+            .map(Some)
+            .map_err(|e| GameError::ResourceLoadError(e.to_string()))
+    }
+}
@@ -0,0 +1,31 @@
+use ggez::{filesystem, Context, GameError, GameResult};
+
+use crate::model::{play_session::PlaySession, repo::session_repository::SessionRepository};
+
+/// ユーザーディレクトリに, モードごとのJSONファイルとして中断したプレイを保存する.
+pub struct FileSessionRepository;
+
+impl FileSessionRepository {
+    fn path(mode: &str) -> String {
+        format!("/session-{}.json", mode)
+    }
+}
+
+impl SessionRepository for FileSessionRepository {
+    fn save(&self, ctx: &mut Context, mode: &str, session: &PlaySession) -> GameResult {
+        let file = filesystem::create(ctx, FileSessionRepository::path(mode))?;
+        serde_json::to_writer(file, session).map_err(|e| GameError::FilesystemError(e.to_string()))
+    }
+
+    fn load(&self, ctx: &mut Context, mode: &str) -> GameResult<Option<PlaySession>> {
+        let path = FileSessionRepository::path(mode);
+        if !filesystem::exists(ctx, &path) {
+            return Ok(None);
+        }
+
+        let file = filesystem::open(ctx, &path)?;
+        serde_json::from_reader(file)
+            .map(Some)
+            .map_err(|e| GameError::ResourceLoadError(e.to_string()))
+    }
+}
@@ -0,0 +1,71 @@
+use ggez::event::{Button, KeyCode};
+
+use crate::model::{
+    control_code::ControlCode,
+    repo::control_code_repository::ControlCodeRepository,
+};
+
+/// 1台のキーボードを左右に分け, ゲームパッドを1台ずつ割り当てる対戦用の操作.
+pub enum VersusControlCodeRepository {
+    PlayerOne,
+    PlayerTwo,
+}
+
+impl ControlCodeRepository for VersusControlCodeRepository {
+    fn key_codes(&self, code: &ControlCode) -> Vec<KeyCode> {
+        use ControlCode::*;
+        use KeyCode::*;
+
+        match self {
+            VersusControlCodeRepository::PlayerOne => match code {
+                MoveLeft | MenuLeft => vec![A],
+                MoveRight | MenuRight => vec![D],
+                SoftDrop | MenuDown => vec![S],
+                HardDrop | MenuUp => vec![W],
+                RotateCounterclockwise => vec![C],
+                RotateClockwise => vec![V],
+                SwapHold => vec![LShift],
+                ToggleStats | ControlCode::Pause | Retry => vec![],
+                MenuEnter => vec![Space],
+                MenuBack => vec![Escape],
+            },
+            VersusControlCodeRepository::PlayerTwo => match code {
+                MoveLeft | MenuLeft => vec![Left],
+                MoveRight | MenuRight => vec![Right],
+                SoftDrop | MenuDown => vec![Down],
+                HardDrop | MenuUp => vec![Up],
+                RotateCounterclockwise => vec![Period],
+                RotateClockwise => vec![Slash],
+                SwapHold => vec![RShift],
+                ToggleStats | ControlCode::Pause | Retry => vec![],
+                MenuEnter => vec![Return],
+                MenuBack => vec![Back],
+            },
+        }
+    }
+
+    fn buttons(&self, code: &ControlCode) -> Vec<Button> {
+        use Button::*;
+        use ControlCode::*;
+
+        match code {
+            MoveLeft | MenuLeft => vec![DPadLeft],
+            MoveRight | MenuRight => vec![DPadRight],
+            SoftDrop | MenuDown => vec![DPadDown],
+            HardDrop | MenuUp => vec![DPadUp],
+            RotateCounterclockwise => vec![South],
+            RotateClockwise => vec![East],
+            SwapHold => vec![LeftTrigger],
+            ToggleStats | Pause | Retry => vec![],
+            MenuEnter => vec![East],
+            MenuBack => vec![Start, Select],
+        }
+    }
+
+    fn gamepad_index(&self) -> Option<usize> {
+        match self {
+            VersusControlCodeRepository::PlayerOne => Some(0),
+            VersusControlCodeRepository::PlayerTwo => Some(1),
+        }
+    }
+}
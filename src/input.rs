@@ -1,53 +1,35 @@
 use ggez::{
-    event::KeyCode,
-    input::{
-        gamepad::{gamepads, gilrs::Button},
-        keyboard,
-    },
+    input::{gamepad::gamepads, keyboard},
     Context,
 };
 
-pub fn pressed_enter(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::Return, KeyCode::Space], &[Button::East])
-}
-
-pub fn pressed_pause(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::Escape], &[Button::Start, Button::Select])
-}
-
-pub fn pressed_up(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::Up, KeyCode::W], &[Button::DPadUp])
-}
-
-pub fn pressed_down(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::Down, KeyCode::S], &[Button::DPadDown])
-}
-
-pub fn pressed_move_left(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::A], &[Button::DPadLeft])
-}
-
-pub fn pressed_move_right(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::D], &[Button::DPadRight])
-}
-
-pub fn pressed_spin_left(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::J], &[Button::South])
-}
-
-pub fn pressed_spin_right(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::K], &[Button::East])
-}
-
-pub fn pressed_hold(ctx: &Context) -> bool {
-    pressed_either(ctx, &[KeyCode::Space], &[Button::LeftTrigger])
-}
-
-fn pressed_either(ctx: &Context, keys: &[KeyCode], buttons: &[Button]) -> bool {
-    let on_keyboard = keys.iter().any(|&key| keyboard::is_key_pressed(ctx, key));
-    let on_pad = buttons
-        .iter()
-        .any(|&btn| gamepads(ctx).any(|(_, pad)| pad.is_pressed(btn)));
+use crate::model::{
+    control_code::ControlCode,
+    repo::control_code_repository::ControlCodeRepository,
+};
 
-    on_keyboard || on_pad
+/// リポジトリの割り当てに従って, 押されている操作を全て探す.
+pub fn find_control_codes<CCR>(ctx: &Context, control_code_repo: &CCR) -> Vec<ControlCode>
+where
+    CCR: ControlCodeRepository + ?Sized,
+{
+    let gamepad_index = control_code_repo.gamepad_index();
+
+    ControlCode::all()
+        .into_iter()
+        .filter(|code| {
+            let keys = control_code_repo.key_codes(code);
+            let buttons = control_code_repo.buttons(code);
+
+            let pressed_key = keys.iter().any(|&key| keyboard::is_key_pressed(ctx, key));
+            let pressed_button = buttons.iter().any(|&btn| {
+                gamepads(ctx)
+                    .enumerate()
+                    .filter(|(idx, _)| gamepad_index.is_none_or(|i| i == *idx))
+                    .any(|(_, (_, pad))| pad.is_pressed(btn))
+            });
+
+            pressed_key || pressed_button
+        })
+        .collect()
 }
@@ -13,7 +13,6 @@ use std::{mem, time::Duration};
 use ggez::{
     event,
     event::{EventHandler, KeyCode, KeyMods},
-    input::{gamepad::gamepads, keyboard},
     timer,
     Context,
     ContextBuilder,
@@ -29,7 +28,7 @@ use crate::{
         input_cache::InputCache,
         repo::control_code_repository::ControlCodeRepository,
     },
-    scene::scene_state::SceneState,
+    scene::scene_stack::SceneStack,
     ticket::{Next, Ticket},
 };
 
@@ -40,17 +39,18 @@ mod model;
 
 pub mod scene;
 
-pub(crate) mod scenes {
-    pub mod router;
-
-    pub mod play40line;
-    pub mod title;
-}
-
 pub(crate) mod tetris {
+    pub mod analysis;
+    pub mod attack;
     pub mod board;
+    pub mod finesse;
     pub mod game;
     pub mod mino_bag;
+    pub mod mode;
+    pub mod opener;
+    pub mod puzzle;
+    pub mod rule;
+    pub mod stats;
 
     pub mod model {
         pub mod mino_entity;
@@ -77,11 +77,14 @@ struct MainState<CCR>
 where
     CCR: ControlCodeRepository,
 {
-    scene_state: Option<SceneState>,
+    scenes: SceneStack,
     asset: Box<Asset>,
     last_measured: Duration,
     input_cache: InputCache,
     control_code_repo: CCR,
+
+    /// 前回の更新から今までにキーボードで入力された文字.
+    typed_chars: String,
 }
 
 impl<CCR: ControlCodeRepository> MainState<CCR> {
@@ -89,61 +92,34 @@ impl<CCR: ControlCodeRepository> MainState<CCR> {
         let mut asset = Asset::load(ctx)?;
 
         Ok(MainState {
-            scene_state: Some(Ticket::ShowTitle.go(ctx, &mut asset)?),
+            scenes: SceneStack::new(Ticket::ShowTitle.go(ctx, &mut asset)?),
             asset,
             last_measured: timer::time_since_start(ctx),
             input_cache: InputCache::new(),
             control_code_repo,
+            typed_chars: String::new(),
         })
     }
 
     fn find_input(&self, ctx: &Context) -> Vec<ControlCode> {
-        let control_code_repo = &self.control_code_repo;
-
-        ControlCode::all()
-            .into_iter()
-            .filter(|code| {
-                let keys = control_code_repo.key_codes(&code);
-                let buttons = control_code_repo.buttons(&code);
-
-                let pressed_key = keys.iter().any(|&key| keyboard::is_key_pressed(ctx, key));
-                let pressed_button = buttons
-                    .iter()
-                    .any(|&btn| gamepads(ctx).any(|(_, pad)| pad.is_pressed(btn)));
-
-                pressed_key || pressed_button
-            })
-            .collect()
+        input::find_control_codes(ctx, &self.control_code_repo)
     }
 }
 
 impl<CCR: ControlCodeRepository> EventHandler for MainState<CCR> {
     fn update(&mut self, ctx: &mut Context) -> GameResult {
         while timer::check_update_time(ctx, FPS) {
-            let scene_state = mem::take(&mut self.scene_state).unwrap();
-
             let now = timer::time_since_start(ctx);
             let delta = now - mem::replace(&mut self.last_measured, now);
 
             let inputs = self.find_input(ctx);
             self.input_cache.receive_inputs(&inputs, &delta);
+            let typed = mem::take(&mut self.typed_chars);
 
-            let next: Next = match scene_state {
-                SceneState::ForTitle { state } => {
-                    scene::title::title_scene::update(ctx, &mut self.input_cache, state, &delta)?
-                }
-            };
-
-            match next {
-                Next::Continue { state } => {
-                    self.scene_state = Some(state);
-                }
-                Next::Transit { ticket } => {
-                    self.scene_state = Some(ticket.go(ctx, &mut self.asset)?);
-                }
-                Next::Exit => {
-                    event::quit(ctx);
-                }
+            self.scenes
+                .update(ctx, &mut self.input_cache, &mut self.asset, &typed, &delta)?;
+            if self.scenes.is_empty() {
+                event::quit(ctx);
             }
         }
 
@@ -151,15 +127,7 @@ impl<CCR: ControlCodeRepository> EventHandler for MainState<CCR> {
     }
 
     fn draw(&mut self, ctx: &mut Context) -> GameResult {
-        if let Some(state) = &self.scene_state {
-            match state {
-                SceneState::ForTitle { state } => {
-                    scene::title::title_scene::draw(ctx, state, &mut self.asset)?;
-                }
-            }
-        }
-
-        Ok(())
+        self.scenes.draw(ctx, &mut self.asset)
     }
 
     fn key_down_event(
@@ -171,4 +139,8 @@ impl<CCR: ControlCodeRepository> EventHandler for MainState<CCR> {
     ) {
         // disable a function to quit on pushing the escape key
     }
+
+    fn text_input_event(&mut self, _ctx: &mut Context, character: char) {
+        self.typed_chars.push(character);
+    }
 }
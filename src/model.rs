@@ -1,9 +1,21 @@
 pub mod control_code;
+pub mod date;
 pub mod device_input;
 pub mod input_cache;
+pub mod lockstep;
+pub mod net_message;
+pub mod play_record;
+pub mod play_session;
+pub mod retry_settings;
+pub mod sandbox_setup;
 pub mod xytuple;
 
 pub mod repo {
     pub mod asset_provider;
     pub mod control_code_repository;
+    pub mod puzzle_repository;
+    pub mod record_repository;
+    pub mod retry_settings_repository;
+    pub mod sandbox_repository;
+    pub mod session_repository;
 }
@@ -1,6 +1,6 @@
 use enum_iterator::IntoEnumIterator;
 
-#[derive(IntoEnumIterator, Hash, PartialEq, Eq, Copy, Clone)]
+#[derive(IntoEnumIterator, Debug, Hash, PartialEq, Eq, Copy, Clone)]
 pub enum ControlCode {
     // In-game
     MoveLeft,
@@ -10,6 +10,9 @@ pub enum ControlCode {
     RotateCounterclockwise,
     RotateClockwise,
     SwapHold,
+    ToggleStats,
+    Pause,
+    Retry,
 
     // Menu
     MenuUp,
@@ -0,0 +1,85 @@
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// # 日付
+///
+/// デイリーチャレンジを日ごとに分けるための, 協定世界時の暦の日付.
+/// 時差があっても, 同じ日に遊んだ人は同じ日付になる.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Date {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn today() -> Date {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+
+        Date::from_days((secs / SECS_PER_DAY) as i64)
+    }
+
+    /// 1970年1月1日から `days` 日後の日付.
+    pub fn from_days(days: i64) -> Date {
+        // 3月から始まる400年周期で数えると, うるう日が周期の最後に来る
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+
+        let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        } as u32;
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date { year, month, day }
+    }
+
+    /// この日に出現するミノの順番を決めるシード.
+    pub fn seed(&self) -> u64 {
+        (self.year * 10_000) as u64 + u64::from(self.month * 100 + self.day)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(0, "1970-01-01")]
+    #[test_case(59, "1970-03-01")]
+    #[test_case(11_016, "2000-02-29")]
+    #[test_case(20_744, "2026-10-18")]
+    fn test_from_days(days: i64, ans: &str) {
+        assert_eq!(Date::from_days(days).to_string(), ans);
+    }
+
+    #[test]
+    fn test_seed_differs_by_day() {
+        assert_eq!(Date::from_days(20_744).seed(), 20_261_018);
+        assert_ne!(
+            Date::from_days(20_744).seed(),
+            Date::from_days(20_745).seed()
+        );
+    }
+}
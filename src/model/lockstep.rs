@@ -0,0 +1,70 @@
+/// # ロックステップ
+///
+/// 自分のフレームが相手から `window` 以上先に進まないように, 進行を待たせる.
+/// 相手から届いたフレームの番号だけを頼りにするため, 通信の遅延が大きいときは両者が揃って待つ.
+pub struct Lockstep {
+    local_frame: u64,
+    remote_frame: u64,
+    window: u64,
+}
+
+impl Lockstep {
+    pub fn new(window: u64) -> Lockstep {
+        Lockstep {
+            local_frame: 0,
+            remote_frame: 0,
+            window,
+        }
+    }
+
+    pub fn can_advance(&self) -> bool {
+        self.local_frame < self.remote_frame + self.window
+    }
+
+    /// 自分のフレームを1つ進め, 相手に知らせるべきフレームの番号を返す.
+    pub fn advance(&mut self) -> u64 {
+        self.local_frame += 1;
+        self.local_frame
+    }
+
+    /// 相手から届いたフレームを受け取る. 順番が入れ替わって届いた古いフレームは無視する.
+    pub fn receive(&mut self, frame: u64) {
+        self.remote_frame = self.remote_frame.max(frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waits_for_remote() {
+        let mut lockstep = Lockstep::new(2);
+
+        assert!(lockstep.can_advance());
+        assert_eq!(lockstep.advance(), 1);
+        assert!(lockstep.can_advance());
+        assert_eq!(lockstep.advance(), 2);
+        assert!(!lockstep.can_advance());
+
+        lockstep.receive(1);
+        assert!(lockstep.can_advance());
+        lockstep.advance();
+        assert!(!lockstep.can_advance());
+    }
+
+    #[test]
+    fn test_ignores_stale_frame() {
+        let mut lockstep = Lockstep::new(1);
+        lockstep.receive(5);
+        lockstep.receive(3);
+
+        let mut frame = 0;
+        for _ in 0..6 {
+            assert!(lockstep.can_advance());
+            frame = lockstep.advance();
+        }
+        assert!(!lockstep.can_advance());
+        assert_eq!(frame, 6);
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+use crate::tetris::{
+    board::{Field, FIELD_UNIT_HEIGHT, FIELD_UNIT_WIDTH},
+    game::Game,
+    model::{
+        mino_entity::MinoEntity,
+        tetrimino::{MinoBlock, Tetrimino},
+    },
+};
+
+/// # 対戦相手と送り合うメッセージ
+///
+/// 1行に1つずつJSONとして送られる.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NetMessage {
+    /// 接続したときにホストから送られる. 両者が同じシードでミノを出現させる.
+    Hello {
+        seed: u64,
+    },
+
+    /// ロックステップのために, 自分が進めたフレームを知らせる.
+    Frame {
+        frame: u64,
+    },
+
+    /// 相殺しきれなかった攻撃のライン数.
+    Garbage {
+        lines: usize,
+    },
+
+    /// 相手の画面に表示するための盤面.
+    Board {
+        snapshot: BoardSnapshot,
+    },
+
+    ToppedOut,
+}
+
+/// # 盤面の写し
+///
+/// 相手の画面に描画するために必要なものだけを持つ.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardSnapshot {
+    /// 上の行から順に並んだ, 各マスのブロックの色.
+    pub rows: Vec<Vec<Option<MinoBlock>>>,
+    pub hold: Option<Tetrimino>,
+    pub next: Vec<Tetrimino>,
+    pub pending_garbage: usize,
+}
+
+impl BoardSnapshot {
+    pub fn new(game: &Game, next_amount: usize, pending_garbage: usize) -> BoardSnapshot {
+        let rows = game
+            .board
+            .field()
+            .iter()
+            .map(|line| line.iter().map(|entity| entity.block()).collect())
+            .collect();
+
+        BoardSnapshot {
+            rows,
+            hold: game.hold_mino,
+            next: game.bag.peek(next_amount),
+            pending_garbage,
+        }
+    }
+
+    /// 写しからフィールドを組み立てる. 大きさが合わない部分は空気で埋める.
+    pub fn field(&self) -> Field {
+        let mut field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+
+        for (y, row) in self.rows.iter().take(FIELD_UNIT_HEIGHT).enumerate() {
+            for (x, block) in row.iter().take(FIELD_UNIT_WIDTH).enumerate() {
+                if let Some(block) = block {
+                    field[y][x] = (*block).into();
+                }
+            }
+        }
+
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut game = Game::with_seed(1);
+        game.hard_drop();
+        game.put_and_spawn();
+
+        let snapshot = BoardSnapshot::new(&game, 5, 3);
+        let message = NetMessage::Board {
+            snapshot: snapshot.clone(),
+        };
+
+        let json = serde_json::to_string(&message).unwrap();
+        let decoded: NetMessage = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, message);
+        assert!(snapshot.field() == game.board.field());
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    cmp::Ordering,
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::tetris::stats::PlayStats;
+
+/// 1回のプレイの結果. 統計と共に保存される.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayRecord {
+    pub mode: String,
+    pub score: usize,
+    pub lines: usize,
+    pub elapsed: Duration,
+    pub stats: PlayStats,
+    pub recorded_at: SystemTime,
+
+    /// モードの目標を達成して終わったかどうか.
+    pub cleared: bool,
+
+    /// 終わったときにモードが大きく表示した言葉. マスターでは得た段位になる.
+    pub title: String,
+}
+
+impl PlayRecord {
+    pub fn new(
+        mode: &str,
+        score: usize,
+        lines: usize,
+        elapsed: Duration,
+        stats: PlayStats,
+        cleared: bool,
+        title: &str,
+    ) -> PlayRecord {
+        PlayRecord {
+            mode: mode.to_owned(),
+            score,
+            lines,
+            elapsed,
+            stats,
+            recorded_at: SystemTime::now(),
+            cleared,
+            title: title.to_owned(),
+        }
+    }
+}
+
+/// # 記録の順位付け
+///
+/// モードによって, 何を競うかが異なる.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Ranking {
+    /// 得点が高いほど上位. 途中で積み上がった記録も含める.
+    HigherScore,
+    /// 早くクリアするほど上位. クリアした記録だけを比べる.
+    ShorterTime,
+    /// 長く生き残るほど上位. 積み上がって終わる記録も含める.
+    LongerTime,
+}
+
+impl Ranking {
+    /// `left` の方が上位のときに `Ordering::Less` を返す.
+    pub fn compare(&self, left: &PlayRecord, right: &PlayRecord) -> Ordering {
+        match self {
+            Ranking::HigherScore => right.score.cmp(&left.score),
+            Ranking::ShorterTime => left.elapsed.cmp(&right.elapsed),
+            Ranking::LongerTime => right.elapsed.cmp(&left.elapsed),
+        }
+    }
+
+    pub fn is_ranked(&self, record: &PlayRecord) -> bool {
+        match self {
+            Ranking::HigherScore | Ranking::LongerTime => true,
+            Ranking::ShorterTime => record.cleared,
+        }
+    }
+
+    /// `records` の中での `record` の順位を1から数える. 順位の付かない記録は `None` になる.
+    pub fn rank(&self, records: &[PlayRecord], record: &PlayRecord) -> Option<usize> {
+        if !self.is_ranked(record) {
+            return None;
+        }
+
+        let better = records
+            .iter()
+            .filter(|other| self.is_ranked(other))
+            .filter(|other| self.compare(other, record) == Ordering::Less)
+            .count();
+
+        Some(better + 1)
+    }
+
+    /// 順位の付く記録だけを, 上位から順に並べる.
+    pub fn leaderboard<'a>(&self, records: &'a [PlayRecord]) -> Vec<&'a PlayRecord> {
+        let mut ranked: Vec<_> = records
+            .iter()
+            .filter(|record| self.is_ranked(record))
+            .collect();
+        ranked.sort_by(|left, right| self.compare(left, right));
+
+        ranked
+    }
+
+    /// 順位の付く記録の中で最も良いもの.
+    pub fn best<'a>(&self, records: &'a [PlayRecord]) -> Option<&'a PlayRecord> {
+        records
+            .iter()
+            .filter(|record| self.is_ranked(record))
+            .min_by(|left, right| self.compare(left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(score: usize, secs: u64, cleared: bool) -> PlayRecord {
+        PlayRecord::new(
+            "test",
+            score,
+            0,
+            Duration::from_secs(secs),
+            PlayStats::new(),
+            cleared,
+            "",
+        )
+    }
+
+    #[test]
+    fn test_rank_by_score() {
+        let records = vec![record(300, 0, false), record(100, 0, true)];
+
+        assert_eq!(
+            Ranking::HigherScore.rank(&records, &record(200, 0, false)),
+            Some(2)
+        );
+        assert_eq!(Ranking::HigherScore.best(&records).unwrap().score, 300);
+    }
+
+    #[test]
+    fn test_rank_by_time_ignores_uncleared() {
+        let records = vec![record(0, 10, false), record(0, 50, true)];
+
+        assert_eq!(
+            Ranking::ShorterTime.rank(&records, &record(0, 30, true)),
+            Some(1)
+        );
+        assert_eq!(
+            Ranking::ShorterTime.rank(&records, &record(0, 5, false)),
+            None
+        );
+        assert_eq!(
+            Ranking::ShorterTime
+                .best(&records)
+                .unwrap()
+                .elapsed
+                .as_secs(),
+            50
+        );
+    }
+
+    #[test]
+    fn test_leaderboard_sorts_ranked_records() {
+        let records = vec![
+            record(0, 40, true),
+            record(0, 10, false),
+            record(0, 20, true),
+        ];
+        let secs: Vec<_> = Ranking::ShorterTime
+            .leaderboard(&records)
+            .iter()
+            .map(|record| record.elapsed.as_secs())
+            .collect();
+
+        assert_eq!(secs, vec![20, 40]);
+    }
+}
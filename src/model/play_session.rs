@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::tetris::{
+    board::{FIELD_UNIT_HEIGHT, FIELD_UNIT_WIDTH},
+    game::Game,
+    model::{
+        mino_entity::MinoEntity,
+        tetrimino::{MinoBlock, Tetrimino},
+    },
+    stats::PlayStats,
+};
+
+/// # 中断したプレイ
+///
+/// 終わりのないモードで, 次に起動したときに続きから遊べるように保存される.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaySession {
+    pub score: usize,
+    pub lines: usize,
+    pub elapsed: Duration,
+    pub stats: PlayStats,
+
+    /// 上の行から順に並んだ, 置かれたブロックの色.
+    pub rows: Vec<Vec<Option<MinoBlock>>>,
+
+    pub dropping: Tetrimino,
+    pub hold: Option<Tetrimino>,
+    /// 落下中のミノの次から順に並んだ, ネクストのミノ.
+    pub queue: Vec<Tetrimino>,
+}
+
+impl PlaySession {
+    pub fn capture(game: &Game, stats: &PlayStats) -> PlaySession {
+        let rows = game
+            .board
+            .confirmed_field
+            .iter()
+            .map(|line| line.iter().map(|entity| entity.block()).collect())
+            .collect();
+
+        PlaySession {
+            score: game.score,
+            lines: game.removed_line_count,
+            elapsed: game.elapsed,
+            stats: stats.clone(),
+            rows,
+            dropping: game.board.dropping,
+            hold: game.hold_mino,
+            queue: game.bag.peek(Tetrimino::all().len()),
+        }
+    }
+
+    /// 保存したときの盤面, ミノと統計に戻す. 大きさが合わない部分は空気で埋める.
+    pub fn restore(&self, game: &mut Game, stats: &mut PlayStats) {
+        game.score = self.score;
+        game.removed_line_count = self.lines;
+        game.elapsed = self.elapsed;
+        *stats = self.stats.clone();
+
+        let mut field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+        for (y, row) in self.rows.iter().take(FIELD_UNIT_HEIGHT).enumerate() {
+            for (x, block) in row.iter().take(FIELD_UNIT_WIDTH).enumerate() {
+                if let Some(block) = block {
+                    field[y][x] = (*block).into();
+                }
+            }
+        }
+        game.board.confirmed_field = field;
+
+        game.hold_mino = self.hold;
+        game.bag.replace_queue(self.queue.clone());
+        game.board.spawn(self.dropping);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restores_captured_game() {
+        let mut game = Game::with_seed(0);
+        game.score = 1200;
+        game.removed_line_count = 8;
+        game.board.confirmed_field[FIELD_UNIT_HEIGHT - 1][3] = MinoEntity::GARBAGE;
+        game.try_swap_hold();
+        let mut stats = PlayStats::new();
+        stats.pieces = 20;
+
+        let json = serde_json::to_string(&PlaySession::capture(&game, &stats)).unwrap();
+        let session: PlaySession = serde_json::from_str(&json).unwrap();
+
+        let mut resumed = Game::with_seed(1);
+        let mut resumed_stats = PlayStats::new();
+        session.restore(&mut resumed, &mut resumed_stats);
+
+        assert_eq!(resumed.score, 1200);
+        assert_eq!(resumed.removed_line_count, 8);
+        assert_eq!(resumed_stats.pieces, 20);
+        assert!(resumed.board.confirmed_field == game.board.confirmed_field);
+        assert_eq!(resumed.board.dropping, game.board.dropping);
+        assert_eq!(resumed.hold_mino, game.hold_mino);
+        assert_eq!(resumed.bag.peek(5), game.bag.peek(5));
+    }
+}
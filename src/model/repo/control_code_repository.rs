@@ -5,4 +5,9 @@ use crate::model::control_code::ControlCode;
 pub trait ControlCodeRepository {
     fn key_codes(&self, code: &ControlCode) -> Vec<KeyCode>;
     fn buttons(&self, code: &ControlCode) -> Vec<Button>;
+
+    /// ボタンの入力を受け付けるゲームパッドの番号. None のときは全てのゲームパッドから受け付ける.
+    fn gamepad_index(&self) -> Option<usize> {
+        None
+    }
 }
@@ -0,0 +1,16 @@
+use std::collections::BTreeSet;
+
+use ggez::{Context, GameResult};
+
+use crate::tetris::puzzle::PuzzlePack;
+
+/// プロフィールを選べるようになるまで, この端末で遊ぶプレイヤーを表すキー.
+pub const LOCAL_PLAYER: &str = "local";
+
+pub trait PuzzleRepository {
+    fn packs(&self, ctx: &mut Context) -> GameResult<Vec<PuzzlePack>>;
+
+    /// `player` が解いたパズルの `PuzzlePack::key`.
+    fn solved(&self, ctx: &mut Context, player: &str) -> GameResult<BTreeSet<String>>;
+    fn mark_solved(&self, ctx: &mut Context, player: &str, key: &str) -> GameResult;
+}
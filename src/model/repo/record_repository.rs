@@ -0,0 +1,8 @@
+use ggez::{Context, GameResult};
+
+use crate::model::play_record::PlayRecord;
+
+pub trait RecordRepository {
+    fn save(&self, ctx: &mut Context, record: PlayRecord) -> GameResult;
+    fn records(&self, ctx: &mut Context, mode: &str) -> GameResult<Vec<PlayRecord>>;
+}
@@ -0,0 +1,9 @@
+use ggez::{Context, GameResult};
+
+use crate::model::retry_settings::RetrySettings;
+
+pub trait RetrySettingsRepository {
+    fn save(&self, ctx: &mut Context, settings: &RetrySettings) -> GameResult;
+    /// 保存されていないときは初期設定を返す.
+    fn load(&self, ctx: &mut Context) -> GameResult<RetrySettings>;
+}
@@ -0,0 +1,8 @@
+use ggez::{Context, GameResult};
+
+use crate::model::sandbox_setup::SandboxSetup;
+
+pub trait SandboxRepository {
+    fn save(&self, ctx: &mut Context, setup: &SandboxSetup) -> GameResult;
+    fn load(&self, ctx: &mut Context) -> GameResult<Option<SandboxSetup>>;
+}
@@ -0,0 +1,8 @@
+use ggez::{Context, GameResult};
+
+use crate::model::play_session::PlaySession;
+
+pub trait SessionRepository {
+    fn save(&self, ctx: &mut Context, mode: &str, session: &PlaySession) -> GameResult;
+    fn load(&self, ctx: &mut Context, mode: &str) -> GameResult<Option<PlaySession>>;
+}
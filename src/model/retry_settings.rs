@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// # やり直しの設定
+///
+/// リトライの入力ですぐに始め直すときの, ミノの順番とカウントダウンの扱い.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetrySettings {
+    /// 直前のプレイと同じ順番でミノを出現させる.
+    pub same_seed: bool,
+    /// カウントダウンを待たずに始める.
+    pub skips_countdown: bool,
+}
+
+impl RetrySettings {
+    /// 直前のプレイのシードが `last_seed` のときに, やり直すプレイで使うシード.
+    /// `None` のときは新しい順番になる.
+    pub fn seed(&self, last_seed: u64) -> Option<u64> {
+        self.same_seed.then_some(last_seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(true, Some(42))]
+    #[test_case(false, None)]
+    fn test_seed(same_seed: bool, expected: Option<u64>) {
+        let settings = RetrySettings {
+            same_seed,
+            skips_countdown: false,
+        };
+
+        assert_eq!(settings.seed(42), expected);
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use crate::tetris::{
+    board::{Field, FIELD_UNIT_HEIGHT, FIELD_UNIT_WIDTH},
+    game::Game,
+    model::{
+        mino_entity::MinoEntity,
+        tetrimino::{MinoBlock, Tetrimino},
+    },
+    rule::Rule,
+};
+
+/// # サンドボックスの盤面
+///
+/// エディタで作った盤面とホールド, ネクストの並び. ファイルに保存して後から読み込める.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SandboxSetup {
+    /// 上の行から順に並んだ, 置かれたブロックの色.
+    pub rows: Vec<Vec<Option<MinoBlock>>>,
+    pub hold: Option<Tetrimino>,
+    /// 最初に操作するミノから順に並べる. 使い切った後は7種1巡のミノが続く.
+    pub queue: Vec<Tetrimino>,
+}
+
+impl SandboxSetup {
+    pub fn capture(field: &Field, hold: Option<Tetrimino>, queue: &[Tetrimino]) -> SandboxSetup {
+        let rows = field
+            .iter()
+            .map(|line| line.iter().map(|entity| entity.block()).collect())
+            .collect();
+
+        SandboxSetup {
+            rows,
+            hold,
+            queue: queue.to_vec(),
+        }
+    }
+
+    /// 保存された盤面. 大きさが合わない部分は空気で埋める.
+    pub fn field(&self) -> Field {
+        let mut field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+        for (y, row) in self.rows.iter().take(FIELD_UNIT_HEIGHT).enumerate() {
+            for (x, block) in row.iter().take(FIELD_UNIT_WIDTH).enumerate() {
+                if let Some(block) = block {
+                    field[y][x] = (*block).into();
+                }
+            }
+        }
+
+        field
+    }
+
+    /// この盤面から始まるゲーム.
+    pub fn create_game(&self) -> Game {
+        let mut game = Game::with_queue(Rule::guideline(), self.queue.clone());
+        game.board.confirmed_field = self.field();
+        game.hold_mino = self.hold;
+
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_from_captured_setup() {
+        let mut field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+        field[FIELD_UNIT_HEIGHT - 1][2] = MinoEntity::GARBAGE;
+        field[FIELD_UNIT_HEIGHT - 2][5] = Tetrimino::T.block().into();
+        let queue = [Tetrimino::I, Tetrimino::O];
+
+        let json =
+            serde_json::to_string(&SandboxSetup::capture(&field, Some(Tetrimino::S), &queue))
+                .unwrap();
+        let setup: SandboxSetup = serde_json::from_str(&json).unwrap();
+        let game = setup.create_game();
+
+        assert!(game.board.confirmed_field == field);
+        assert_eq!(game.hold_mino, Some(Tetrimino::S));
+        assert_eq!(game.board.dropping, Tetrimino::I);
+        assert_eq!(game.bag.peek(1), vec![Tetrimino::O]);
+    }
+}
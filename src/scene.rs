@@ -1,13 +1,53 @@
 pub mod animation_property;
-pub mod scene_state;
+pub mod scene_stack;
 pub mod ticket;
 pub mod timer;
 
-pub mod fortyline {
-    pub mod fortyline_scene;
+pub mod daily {
+    pub mod daily_scene;
+}
+
+pub mod pause {
+    pub mod pause_scene;
+}
+
+pub mod mode_select {
+    pub mod mode_select_scene;
+}
+
+pub mod online {
+    pub mod lobby_scene;
+    pub mod online_versus_scene;
+}
+
+pub mod play {
+    pub mod play_scene;
+}
+
+pub mod puzzle {
+    pub mod puzzle_scene;
+    pub mod puzzle_select_scene;
+}
+
+pub mod playfield {
+    pub mod controller;
+    pub mod effect;
+    pub mod renderer;
+}
+
+pub mod results {
+    pub mod results_scene;
+}
+
+pub mod sandbox {
+    pub mod sandbox_scene;
 }
 
 pub mod title {
     pub mod selected_item;
     pub mod title_scene;
 }
+
+pub mod versus {
+    pub mod versus_scene;
+}
@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use ggez::{
+    graphics,
+    graphics::{DrawParam, PxScale, Text, TextFragment},
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::{audio::Se, Asset},
+    infra::repo::file_record_repository::FileRecordRepository,
+    model::{
+        control_code::ControlCode,
+        date::Date,
+        input_cache::InputCache,
+        repo::record_repository::RecordRepository,
+    },
+    scene::{
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+    },
+    tetris::mode::{format_time, ModeKind},
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const TITLE_FONT_SIZE: f32 = 48.;
+const ITEM_FONT_SIZE: f32 = 32.;
+const ITEM_LINE_HEIGHT: f32 = 44.;
+/// 順位表に並べる記録の数.
+const LEADERBOARD_AMOUNT: usize = 10;
+
+/// # デイリーチャレンジ
+///
+/// 今日の日付から決まる順番のミノで40ラインを遊ぶ. 順位表は日ごとに分かれる.
+pub struct DailyState {
+    kind: ModeKind,
+    /// 今日の記録のタイムを, 速い順に並べたもの.
+    times: Vec<Duration>,
+}
+
+impl Scene for DailyState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
+pub fn init(ctx: &mut Context, _: &mut Asset) -> GameResult<DailyState> {
+    let kind = ModeKind::Daily {
+        date: Date::today(),
+    };
+    let records = FileRecordRepository.records(ctx, &kind.record_name())?;
+    let times = kind
+        .ranking()
+        .leaderboard(&records)
+        .iter()
+        .take(LEADERBOARD_AMOUNT)
+        .map(|record| record.elapsed)
+        .collect();
+
+    Ok(DailyState { kind, times })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: DailyState,
+    _: &Duration,
+) -> GameResult<Next> {
+    if input_cache.has_pushed(&ControlCode::MenuEnter) {
+        asset.audio.play_se(ctx, Se::MenuClick)?;
+        return Ok(Next::transit(Ticket::Play { kind: state.kind }));
+    }
+    if input_cache.has_pushed(&ControlCode::MenuBack) {
+        return Ok(Next::transit(Ticket::ShowTitle));
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+pub fn draw(ctx: &mut Context, state: &DailyState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    let title = Text::new(
+        TextFragment::new(format!("{} - 40 LINES", state.kind.name()))
+            .font(asset.font.vt323)
+            .scale(PxScale::from(TITLE_FONT_SIZE)),
+    );
+    let title_x = WINDOW_WIDTH / 2. - title.width(ctx) / 2.;
+    graphics::draw(
+        ctx,
+        &title,
+        DrawParam::default().dest([title_x, WINDOW_HEIGHT / 8.]),
+    )?;
+
+    let rows = if state.times.is_empty() {
+        vec!["NO RECORDS YET TODAY".to_owned()]
+    } else {
+        state
+            .times
+            .iter()
+            .enumerate()
+            .map(|(idx, time)| format!("#{: <2} {}", idx + 1, format_time(time)))
+            .collect()
+    };
+    let guide = "ENTER: PLAY / BACK: TITLE".to_owned();
+
+    for (row, line) in rows.iter().chain(Some(&guide)).enumerate() {
+        let text = Text::new(
+            TextFragment::new(line.as_str())
+                .font(asset.font.vt323)
+                .scale(PxScale::from(ITEM_FONT_SIZE)),
+        );
+        let x = WINDOW_WIDTH / 2. - text.width(ctx) / 2.;
+        // 案内だけは順位表から1行空けて置く
+        let gap = if row == rows.len() { 1. } else { 0. };
+        let y = WINDOW_HEIGHT / 4. + (row as f32 + gap) * ITEM_LINE_HEIGHT;
+
+        graphics::draw(ctx, &text, DrawParam::default().dest([x, y]))?;
+    }
+
+    Ok(())
+}
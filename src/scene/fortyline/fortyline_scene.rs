@@ -1,13 +0,0 @@
-use ggez::Context;
-
-pub struct FortyLineState {
-    draw_state: FortyLineDrawState,
-}
-
-struct FortyLineDrawState {}
-
-fn init(_: &mut Context, _: &FortyLineState) {}
-
-fn update(_: &mut Context, _: &mut FortyLineState) {}
-
-fn render(_: &mut Context, _: &FortyLineState) {}
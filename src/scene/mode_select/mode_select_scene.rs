@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use ggez::{
+    graphics,
+    graphics::{DrawParam, PxScale, Text, TextFragment},
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::{audio::Se, Asset},
+    model::{control_code::ControlCode, input_cache::InputCache},
+    scene::{
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+    },
+    tetris::mode::ModeKind,
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const ITEM_FONT_SIZE: f32 = 32.;
+const ITEM_LINE_HEIGHT: f32 = 50.;
+/// 一度に並べるモードの数. これより多いときはカーソルに合わせて流れる.
+const VISIBLE_ITEM_AMOUNT: usize = 9;
+
+pub struct ModeSelectState {
+    modes: Vec<ModeKind>,
+    cursor: usize,
+}
+
+impl Scene for ModeSelectState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
+pub fn init(_: &mut Context, _: &mut Asset) -> GameResult<ModeSelectState> {
+    Ok(ModeSelectState {
+        modes: ModeKind::presets(),
+        cursor: 0,
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: ModeSelectState,
+    _: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    if input_cache.has_pushed(&ControlCode::MenuUp) && state.cursor > 0 {
+        state.cursor -= 1;
+    }
+    if input_cache.has_pushed(&ControlCode::MenuDown) && state.cursor + 1 < state.modes.len() {
+        state.cursor += 1;
+    }
+
+    if input_cache.has_pushed(&ControlCode::MenuEnter) {
+        asset.audio.play_se(ctx, Se::MenuClick)?;
+        let kind = state.modes[state.cursor];
+
+        return Ok(Next::transit(Ticket::Play { kind }));
+    }
+    if input_cache.has_pushed(&ControlCode::MenuBack) {
+        return Ok(Next::transit(Ticket::ShowTitle));
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+pub fn draw(ctx: &mut Context, state: &ModeSelectState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    let first = (state.cursor + 1).saturating_sub(VISIBLE_ITEM_AMOUNT);
+    let visible = state
+        .modes
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(VISIBLE_ITEM_AMOUNT);
+
+    for (row, (idx, mode)) in visible.enumerate() {
+        let text = Text::new(
+            TextFragment::new(mode.name())
+                .font(asset.font.vt323)
+                .scale(PxScale::from(ITEM_FONT_SIZE)),
+        );
+        let x = WINDOW_WIDTH / 2. - text.width(ctx) / 2.;
+        let y = WINDOW_HEIGHT / 4. + (row as f32) * ITEM_LINE_HEIGHT;
+
+        graphics::draw(ctx, &text, DrawParam::default().dest([x, y]))?;
+
+        if idx == state.cursor {
+            let cursor_scale = 0.5;
+            let cursor_y = y + text.height(ctx) / 2.
+                - f32::from(asset.image.cursor.height()) * cursor_scale / 2.;
+
+            graphics::draw(
+                ctx,
+                &asset.image.cursor,
+                DrawParam::default()
+                    .dest([x - 30., cursor_y])
+                    .scale([cursor_scale, cursor_scale]),
+            )?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use std::{
+    net::{SocketAddr, TcpListener},
+    time::Duration,
+};
+
+use ggez::{
+    graphics,
+    graphics::{DrawParam, PxScale, Text, TextFragment},
+    Context,
+    GameResult,
+};
+use rand::random;
+
+use crate::{
+    asset::{audio::Se, Asset},
+    infra::net::{tcp_peer, tcp_peer::TcpPeer},
+    model::{control_code::ControlCode, input_cache::InputCache, net_message::NetMessage},
+    scene::{
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+    },
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const ITEM_FONT_SIZE: f32 = 32.;
+const MESSAGE_FONT_SIZE: f32 = 24.;
+const BACKSPACE: char = '\u{8}';
+
+pub struct LobbyState {
+    cursor: LobbyItem,
+    /// 参加するときに接続する `ip:port`. 数字と `.`, `:` だけを入力できる.
+    address: String,
+    phase: LobbyPhase,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum LobbyItem {
+    Host,
+    Join,
+}
+
+enum LobbyPhase {
+    Selecting { message: Option<String> },
+    Hosting { listener: TcpListener },
+    Joining { peer: TcpPeer },
+}
+
+impl Scene for LobbyState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        typed: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, typed, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
+pub fn init(_: &mut Context, _: &mut Asset) -> GameResult<LobbyState> {
+    Ok(LobbyState {
+        cursor: LobbyItem::Host,
+        address: format!("127.0.0.1:{}", tcp_peer::DEFAULT_PORT),
+        phase: LobbyPhase::Selecting { message: None },
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    typed: &str,
+    state: LobbyState,
+    _: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    state.phase = match state.phase {
+        LobbyPhase::Selecting { message } => {
+            if input_cache.has_pushed(&ControlCode::MenuBack) {
+                return Ok(Next::transit(Ticket::ShowTitle));
+            }
+
+            if input_cache.has_pushed(&ControlCode::MenuUp) {
+                state.cursor = LobbyItem::Host;
+            }
+            if input_cache.has_pushed(&ControlCode::MenuDown) {
+                state.cursor = LobbyItem::Join;
+            }
+            if state.cursor == LobbyItem::Join {
+                edit_address(&mut state.address, typed);
+            }
+
+            if input_cache.has_pushed(&ControlCode::MenuEnter) {
+                asset.audio.play_se(ctx, Se::MenuClick)?;
+
+                match state.cursor {
+                    LobbyItem::Host => host(),
+                    LobbyItem::Join => join(&state.address),
+                }
+            } else {
+                LobbyPhase::Selecting { message }
+            }
+        }
+        LobbyPhase::Hosting { listener } => {
+            if input_cache.has_pushed(&ControlCode::MenuBack) {
+                LobbyPhase::Selecting { message: None }
+            } else {
+                match TcpPeer::accept(&listener) {
+                    Ok(Some(mut peer)) => {
+                        let seed = random::<u64>();
+
+                        match peer.send(&NetMessage::Hello { seed }) {
+                            Ok(_) => return Ok(Next::transit(Ticket::PlayOnline { peer, seed })),
+                            Err(e) => failed("FAILED TO CONNECT", e),
+                        }
+                    }
+                    Ok(None) => LobbyPhase::Hosting { listener },
+                    Err(e) => failed("FAILED TO ACCEPT", e),
+                }
+            }
+        }
+        LobbyPhase::Joining { mut peer } => {
+            if input_cache.has_pushed(&ControlCode::MenuBack) {
+                LobbyPhase::Selecting { message: None }
+            } else {
+                match peer.receive() {
+                    Ok(messages) => {
+                        let seed = messages.iter().find_map(|message| match message {
+                            NetMessage::Hello { seed } => Some(*seed),
+                            _ => None,
+                        });
+
+                        match seed {
+                            Some(seed) => {
+                                return Ok(Next::transit(Ticket::PlayOnline { peer, seed }));
+                            }
+                            None => LobbyPhase::Joining { peer },
+                        }
+                    }
+                    Err(e) => failed("DISCONNECTED", e),
+                }
+            }
+        }
+    };
+
+    Ok(Next::do_continue(state))
+}
+
+fn host() -> LobbyPhase {
+    match tcp_peer::listen(tcp_peer::DEFAULT_PORT) {
+        Ok(listener) => LobbyPhase::Hosting { listener },
+        Err(e) => failed("FAILED TO HOST", e),
+    }
+}
+
+fn join(address: &str) -> LobbyPhase {
+    let addr = match address.parse::<SocketAddr>() {
+        Ok(addr) => addr,
+        Err(_) => {
+            return LobbyPhase::Selecting {
+                message: Some("INVALID ADDRESS".to_owned()),
+            }
+        }
+    };
+
+    match TcpPeer::connect(&addr) {
+        Ok(peer) => LobbyPhase::Joining { peer },
+        Err(e) => failed("FAILED TO JOIN", e),
+    }
+}
+
+fn failed(reason: &str, error: std::io::Error) -> LobbyPhase {
+    LobbyPhase::Selecting {
+        message: Some(format!("{}: {}", reason, error)),
+    }
+}
+
+fn edit_address(address: &mut String, typed: &str) {
+    for c in typed.chars() {
+        match c {
+            BACKSPACE => {
+                address.pop();
+            }
+            c if c.is_ascii_digit() || c == '.' || c == ':' => {
+                address.push(c);
+            }
+            _ => (),
+        }
+    }
+}
+
+pub fn draw(ctx: &mut Context, state: &LobbyState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    let items = [
+        (
+            LobbyItem::Host,
+            format!("Host (port {})", tcp_peer::DEFAULT_PORT),
+        ),
+        (LobbyItem::Join, format!("Join {}", state.address)),
+    ];
+
+    for (idx, (item, label)) in items.iter().enumerate() {
+        let text = Text::new(
+            TextFragment::new(label.as_str())
+                .font(asset.font.vt323)
+                .scale(PxScale::from(ITEM_FONT_SIZE)),
+        );
+        let x = WINDOW_WIDTH / 2. - text.width(ctx) / 2.;
+        let y = WINDOW_HEIGHT / 3. + (50 * idx) as f32;
+
+        graphics::draw(ctx, &text, DrawParam::default().dest([x, y]))?;
+
+        if item == &state.cursor {
+            let cursor_scale = 0.5;
+            let cursor_y = y + text.height(ctx) / 2.
+                - f32::from(asset.image.cursor.height()) * cursor_scale / 2.;
+
+            graphics::draw(
+                ctx,
+                &asset.image.cursor,
+                DrawParam::default()
+                    .dest([x - 30., cursor_y])
+                    .scale([cursor_scale, cursor_scale]),
+            )?;
+        }
+    }
+
+    let message = match &state.phase {
+        LobbyPhase::Selecting { message } => message.clone(),
+        LobbyPhase::Hosting { .. } => Some("WAITING FOR OPPONENT...".to_owned()),
+        LobbyPhase::Joining { .. } => Some("WAITING FOR HOST...".to_owned()),
+    };
+    if let Some(message) = message {
+        let text = Text::new(
+            TextFragment::new(message)
+                .font(asset.font.vt323)
+                .scale(PxScale::from(MESSAGE_FONT_SIZE)),
+        );
+        graphics::draw(
+            ctx,
+            &text,
+            DrawParam::default().dest([
+                WINDOW_WIDTH / 2. - text.width(ctx) / 2.,
+                WINDOW_HEIGHT * 2. / 3.,
+            ]),
+        )?;
+    }
+
+    Ok(())
+}
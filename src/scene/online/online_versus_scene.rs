@@ -0,0 +1,360 @@
+use std::time::Duration;
+
+use ggez::{graphics, Context, GameResult};
+use rand::Rng;
+
+use crate::{
+    asset::{
+        audio::{Bgm, Se},
+        Asset,
+    },
+    infra::net::tcp_peer::TcpPeer,
+    model::{
+        control_code::ControlCode,
+        input_cache::InputCache,
+        lockstep::Lockstep,
+        net_message::{BoardSnapshot, NetMessage},
+    },
+    scene::{
+        playfield::{
+            controller::{GameController, Operation},
+            renderer,
+            renderer::FieldLayout,
+        },
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+        timer::Timer,
+    },
+    tetris::{
+        attack::{AttackTable, IncomingGarbage},
+        board::FIELD_UNIT_WIDTH,
+        game::{DroppedOrNothing, Game, GameEvent},
+    },
+};
+
+const COUNTDOWN_SEC: u64 = 3;
+const BLOCK_LENGTH: f32 = 24.;
+const VISIBLE_NEXT_MINO_AMOUNT: usize = 5;
+
+/// 相手より先に進めてよいフレーム数. 60FPSで約100ms.
+const LOCKSTEP_WINDOW: u64 = 6;
+
+pub struct OnlineVersusState {
+    peer: TcpPeer,
+    lockstep: Lockstep,
+    controller: GameController,
+    attack_table: AttackTable,
+
+    game: Game,
+    garbage: IncomingGarbage,
+    layout: FieldLayout,
+
+    /// 相手から最後に届いた盤面. まだ届いていなければ空のフィールドを表示する.
+    remote: Option<BoardSnapshot>,
+    remote_layout: FieldLayout,
+
+    phase: Phase,
+}
+
+enum Phase {
+    Countdown { timer: Timer, remaining: u64 },
+    Playing,
+    Finished { won: bool },
+    Disconnected,
+}
+
+impl Scene for OnlineVersusState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
+pub fn init(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    peer: TcpPeer,
+    seed: u64,
+) -> GameResult<OnlineVersusState> {
+    asset.audio.stop_bgm();
+    asset.audio.play_se(ctx, Se::CountdownTick)?;
+
+    Ok(OnlineVersusState {
+        peer,
+        lockstep: Lockstep::new(LOCKSTEP_WINDOW),
+        controller: GameController::default(),
+        attack_table: AttackTable::guideline(),
+        game: Game::with_seed(seed),
+        garbage: IncomingGarbage::new(),
+        layout: FieldLayout::split_screen(0, BLOCK_LENGTH),
+        remote: None,
+        remote_layout: FieldLayout::split_screen(1, BLOCK_LENGTH),
+        phase: Phase::Countdown {
+            timer: Timer::repeat(Duration::from_secs(1), COUNTDOWN_SEC as u32),
+            remaining: COUNTDOWN_SEC,
+        },
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: OnlineVersusState,
+    delta: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    if is_in_match(&state.phase) {
+        if let Err(phase) = receive_messages(&mut state) {
+            asset.audio.stop_bgm();
+            state.phase = phase;
+        }
+    }
+
+    if is_in_match(&state.phase) && state.lockstep.can_advance() {
+        let frame = state.lockstep.advance();
+
+        let next_phase = if state.peer.send(&NetMessage::Frame { frame }).is_err() {
+            Some(Phase::Disconnected)
+        } else {
+            advance(ctx, input_cache, asset, &mut state, delta)?
+        };
+        if let Some(phase) = next_phase {
+            state.phase = phase;
+        }
+
+        if !is_in_match(&state.phase) {
+            asset.audio.stop_bgm();
+        }
+    }
+
+    if !is_in_match(&state.phase)
+        && (input_cache.has_pushed(&ControlCode::MenuEnter)
+            || input_cache.has_pushed(&ControlCode::MenuBack))
+    {
+        asset.audio.play_se(ctx, Se::MenuClick)?;
+        return Ok(Next::transit(Ticket::ShowTitle));
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+fn is_in_match(phase: &Phase) -> bool {
+    matches!(phase, Phase::Countdown { .. } | Phase::Playing)
+}
+
+/// 届いたメッセージを反映する. 対戦が終わったときは次の段階を `Err` で返す.
+fn receive_messages(state: &mut OnlineVersusState) -> Result<(), Phase> {
+    let messages = state.peer.receive().map_err(|_| Phase::Disconnected)?;
+
+    for message in messages {
+        match message {
+            NetMessage::Frame { frame } => state.lockstep.receive(frame),
+            NetMessage::Garbage { lines } => state.garbage.receive(lines),
+            NetMessage::Board { snapshot } => state.remote = Some(snapshot),
+            NetMessage::ToppedOut => return Err(Phase::Finished { won: true }),
+            NetMessage::Hello { .. } => (),
+        }
+    }
+
+    Ok(())
+}
+
+/// ロックステップで許された1フレーム分だけゲームを進める.
+fn advance(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: &mut OnlineVersusState,
+    delta: &Duration,
+) -> GameResult<Option<Phase>> {
+    if let Phase::Countdown {
+        ref mut timer,
+        ref mut remaining,
+    } = state.phase
+    {
+        timer.elapse(*delta);
+
+        if timer.consume_if_beep() {
+            *remaining -= 1;
+
+            if *remaining == 0 {
+                asset.audio.play_bgm(ctx, Bgm::InGame)?;
+                asset.audio.play_se(ctx, Se::GameStart)?;
+                return Ok(Some(Phase::Playing));
+            }
+
+            asset.audio.play_se(ctx, Se::CountdownTick)?;
+        }
+
+        return Ok(None);
+    }
+
+    let mut operations = state.controller.operate(&mut state.game, input_cache);
+    let mut changed = !operations.is_empty();
+
+    let has_put = operations.iter().any(|op| matches!(op, Operation::Put(_)));
+    if !has_put {
+        if let DroppedOrNothing::Dropped(dropped) = state.game.elapse(*delta) {
+            changed = true;
+
+            if let Some(removed_lines) = dropped {
+                operations.push(Operation::Put(removed_lines));
+            }
+        }
+    }
+
+    let mut topped_out = false;
+    for operation in operations {
+        match operation {
+            Operation::Moved => asset.audio.play_se(ctx, Se::MinoMove)?,
+            Operation::Spun => asset.audio.play_se(ctx, Se::MinoSpin)?,
+            Operation::SoftDropped => asset.audio.play_se(ctx, Se::MinoSoftDrop)?,
+            Operation::Held => (),
+            Operation::Put(removed_lines) => {
+                asset.audio.play_se(ctx, Se::MinoHardDrop)?;
+
+                if !state.game.put_and_spawn() {
+                    topped_out = true;
+                }
+                if !removed_lines.is_empty() {
+                    asset.audio.play_se(ctx, Se::RemoveLine)?;
+                    state.game.remove_lines();
+                }
+            }
+        }
+    }
+
+    let mut outgoing = Vec::new();
+    let mut sent = 0;
+    for event in state.game.take_events() {
+        match event {
+            GameEvent::Put {
+                reward: Some(reward),
+                ..
+            } => {
+                let attack = state.attack_table.attack(&reward);
+                sent += state.garbage.offset(attack);
+            }
+            GameEvent::Put { reward: None, .. } => {
+                for lines in state.garbage.take() {
+                    let hole_x = rand::thread_rng().gen_range(0..FIELD_UNIT_WIDTH);
+                    if !state.game.raise_garbage(lines, hole_x) {
+                        topped_out = true;
+                    }
+                }
+            }
+            GameEvent::ToppedOut => topped_out = true,
+            GameEvent::Held { .. } | GameEvent::RanOut => (),
+        }
+    }
+
+    if sent > 0 {
+        outgoing.push(NetMessage::Garbage { lines: sent });
+    }
+    if changed {
+        let snapshot = BoardSnapshot::new(
+            &state.game,
+            VISIBLE_NEXT_MINO_AMOUNT,
+            state.garbage.pending(),
+        );
+        outgoing.push(NetMessage::Board { snapshot });
+    }
+    if topped_out {
+        outgoing.push(NetMessage::ToppedOut);
+    }
+
+    for message in &outgoing {
+        if state.peer.send(message).is_err() {
+            return Ok(Some(Phase::Disconnected));
+        }
+    }
+
+    Ok(if topped_out {
+        Some(Phase::Finished { won: false })
+    } else {
+        None
+    })
+}
+
+pub fn draw(ctx: &mut Context, state: &OnlineVersusState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    draw_local(ctx, asset, state)?;
+    draw_remote(ctx, asset, state)?;
+
+    match state.phase {
+        Phase::Countdown { remaining, .. } => {
+            renderer::draw_count_down(ctx, asset, remaining)?;
+        }
+        Phase::Playing => (),
+        Phase::Finished { won } => {
+            let text = if won { "YOU WIN" } else { "YOU LOSE" };
+            renderer::draw_result(ctx, asset, text, &["ENTER: TITLE"])?;
+        }
+        Phase::Disconnected => {
+            renderer::draw_result(ctx, asset, "CONNECTION LOST", &["ENTER: TITLE"])?;
+        }
+    }
+
+    Ok(())
+}
+
+fn draw_local(ctx: &mut Context, asset: &mut Asset, state: &OnlineVersusState) -> GameResult {
+    let layout = &state.layout;
+    let game = &state.game;
+
+    renderer::draw_field(ctx, asset, layout)?;
+    renderer::draw_garbage_meter(ctx, layout, state.garbage.pending())?;
+
+    let label = if is_in_match(&state.phase) && !state.lockstep.can_advance() {
+        "YOU (WAITING...)"
+    } else {
+        "YOU"
+    };
+    renderer::draw_label(ctx, asset, layout, label)?;
+
+    if !matches!(state.phase, Phase::Countdown { .. }) {
+        renderer::draw_hold_panel(ctx, asset, layout, game.hold_mino)?;
+        renderer::draw_next_panel(
+            ctx,
+            asset,
+            layout,
+            game.bag.peek(VISIBLE_NEXT_MINO_AMOUNT).as_slice(),
+        )?;
+        renderer::draw_minos(ctx, asset, layout, &game.board, true)?;
+
+        if matches!(state.phase, Phase::Playing) {
+            renderer::draw_ghost(ctx, layout, &game.board)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn draw_remote(ctx: &mut Context, asset: &mut Asset, state: &OnlineVersusState) -> GameResult {
+    let layout = &state.remote_layout;
+
+    renderer::draw_field(ctx, asset, layout)?;
+    renderer::draw_label(ctx, asset, layout, "OPPONENT")?;
+
+    if let Some(ref snapshot) = state.remote {
+        renderer::draw_garbage_meter(ctx, layout, snapshot.pending_garbage)?;
+        renderer::draw_hold_panel(ctx, asset, layout, snapshot.hold)?;
+        renderer::draw_next_panel(ctx, asset, layout, &snapshot.next)?;
+        renderer::draw_blocks(ctx, asset, layout, &snapshot.field(), &[])?;
+    }
+
+    Ok(())
+}
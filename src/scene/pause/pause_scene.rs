@@ -0,0 +1,221 @@
+use std::time::Duration;
+
+use ggez::{
+    graphics,
+    graphics::{DrawMode, DrawParam, PxScale, Rect, Text, TextFragment},
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::{audio::Se, Asset},
+    infra::repo::file_retry_settings_repository::FileRetrySettingsRepository,
+    model::{
+        control_code::ControlCode,
+        input_cache::InputCache,
+        repo::retry_settings_repository::RetrySettingsRepository,
+        retry_settings::RetrySettings,
+    },
+    scene::{
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+    },
+    tetris::mode::ModeKind,
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const TITLE_FONT_SIZE: f32 = 72.;
+const ITEM_FONT_SIZE: f32 = 36.;
+const ITEM_LINE_HEIGHT: f32 = 56.;
+
+/// # ポーズメニュー
+///
+/// プレイ中のシーンに重ねて積まれる. 下のシーンは止まったまま残り, 再開するとそのまま続く.
+pub struct PauseState {
+    /// やり直すときに始めるモードと, 止めたプレイのシード.
+    kind: ModeKind,
+    seed: u64,
+    cursor: PauseItem,
+    retry_settings: RetrySettings,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum PauseItem {
+    Resume,
+    Restart,
+    SameSeed,
+    SkipsCountdown,
+    Quit,
+}
+
+impl PauseItem {
+    const ALL: [PauseItem; 5] = [
+        PauseItem::Resume,
+        PauseItem::Restart,
+        PauseItem::SameSeed,
+        PauseItem::SkipsCountdown,
+        PauseItem::Quit,
+    ];
+
+    fn name(&self, settings: &RetrySettings) -> String {
+        let on_off = |on: bool| if on { "ON" } else { "OFF" };
+
+        match self {
+            PauseItem::Resume => "Resume".to_owned(),
+            PauseItem::Restart => "Restart".to_owned(),
+            PauseItem::SameSeed => format!("Retry with Same Seed: {}", on_off(settings.same_seed)),
+            PauseItem::SkipsCountdown => {
+                format!(
+                    "Retry without Countdown: {}",
+                    on_off(settings.skips_countdown)
+                )
+            }
+            PauseItem::Quit => "Quit to Title".to_owned(),
+        }
+    }
+
+    fn index(&self) -> usize {
+        PauseItem::ALL.iter().position(|item| item == self).unwrap()
+    }
+}
+
+impl Scene for PauseState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
+
+pub fn init(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    kind: ModeKind,
+    seed: u64,
+) -> GameResult<PauseState> {
+    asset.audio.play_se(ctx, Se::MenuClick)?;
+
+    Ok(PauseState {
+        kind,
+        seed,
+        cursor: PauseItem::Resume,
+        retry_settings: FileRetrySettingsRepository.load(ctx)?,
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: PauseState,
+    _: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    if input_cache.has_pushed(&ControlCode::MenuUp) {
+        if let Some(index) = state.cursor.index().checked_sub(1) {
+            state.cursor = PauseItem::ALL[index];
+        }
+    }
+    if input_cache.has_pushed(&ControlCode::MenuDown) {
+        if let Some(&item) = PauseItem::ALL.get(state.cursor.index() + 1) {
+            state.cursor = item;
+        }
+    }
+
+    let retry = Ticket::Retry {
+        kind: state.kind,
+        seed: state.seed,
+    };
+
+    if input_cache.has_pushed(&ControlCode::Retry) {
+        return Ok(Next::transit(retry));
+    }
+    if input_cache.has_pushed(&ControlCode::Pause) || input_cache.has_pushed(&ControlCode::MenuBack)
+    {
+        return Ok(Next::pop());
+    }
+
+    if input_cache.has_pushed(&ControlCode::MenuEnter) {
+        asset.audio.play_se(ctx, Se::MenuClick)?;
+
+        let settings = &mut state.retry_settings;
+        match state.cursor {
+            PauseItem::Resume => return Ok(Next::pop()),
+            PauseItem::Restart => return Ok(Next::transit(retry)),
+            PauseItem::SameSeed => settings.same_seed = !settings.same_seed,
+            PauseItem::SkipsCountdown => settings.skips_countdown = !settings.skips_countdown,
+            PauseItem::Quit => return Ok(Next::transit(Ticket::ShowTitle)),
+        }
+
+        FileRetrySettingsRepository.save(ctx, settings)?;
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+pub fn draw(ctx: &mut Context, state: &PauseState, asset: &mut Asset) -> GameResult {
+    let rect = graphics::Mesh::new_rectangle(
+        ctx,
+        DrawMode::fill(),
+        Rect::new(0., 0., WINDOW_WIDTH, WINDOW_HEIGHT),
+        graphics::Color::new(0., 0., 0., 0.6),
+    )?;
+    graphics::draw(ctx, &rect, DrawParam::default())?;
+
+    let title = Text::new(
+        TextFragment::new("PAUSED")
+            .font(asset.font.vt323)
+            .scale(PxScale::from(TITLE_FONT_SIZE)),
+    );
+    graphics::draw(
+        ctx,
+        &title,
+        DrawParam::default().dest([
+            WINDOW_WIDTH / 2. - title.width(ctx) / 2.,
+            WINDOW_HEIGHT / 3. - title.height(ctx),
+        ]),
+    )?;
+
+    for (idx, item) in PauseItem::ALL.iter().enumerate() {
+        let text = Text::new(
+            TextFragment::new(item.name(&state.retry_settings))
+                .font(asset.font.vt323)
+                .scale(PxScale::from(ITEM_FONT_SIZE)),
+        );
+        let x = WINDOW_WIDTH / 2. - text.width(ctx) / 2.;
+        let y = WINDOW_HEIGHT / 2. + (idx as f32) * ITEM_LINE_HEIGHT;
+
+        graphics::draw(ctx, &text, DrawParam::default().dest([x, y]))?;
+
+        if *item == state.cursor {
+            let cursor_scale = 0.5;
+            let cursor_y = y + text.height(ctx) / 2.
+                - f32::from(asset.image.cursor.height()) * cursor_scale / 2.;
+
+            graphics::draw(
+                ctx,
+                &asset.image.cursor,
+                DrawParam::default()
+                    .dest([x - 30., cursor_y])
+                    .scale([cursor_scale, cursor_scale]),
+            )?;
+        }
+    }
+
+    Ok(())
+}
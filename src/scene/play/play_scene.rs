@@ -0,0 +1,661 @@
+use std::time::Duration;
+
+use ggez::{
+    graphics,
+    graphics::{DrawParam, PxScale},
+    Context,
+    GameResult,
+};
+use itertools::Itertools;
+use rand::random;
+
+use crate::{
+    asset::{
+        audio::{Bgm, Se},
+        Asset,
+    },
+    infra::repo::{
+        file_record_repository::FileRecordRepository,
+        file_retry_settings_repository::FileRetrySettingsRepository,
+        file_session_repository::FileSessionRepository,
+    },
+    model::{
+        control_code::ControlCode,
+        input_cache::InputCache,
+        play_record::PlayRecord,
+        play_session::PlaySession,
+        repo::{
+            record_repository::RecordRepository,
+            retry_settings_repository::RetrySettingsRepository,
+            session_repository::SessionRepository,
+        },
+    },
+    scene::{
+        animation_property::AnimationProperties,
+        playfield::{
+            controller::{GameController, Operation},
+            effect::{DroppingWindbreakParticle, RemovingLineAnimation},
+            renderer,
+            renderer::FieldLayout,
+        },
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+        timer::Timer,
+    },
+    tetris::{
+        analysis::BoardAnalysis,
+        board::FIELD_VISIBLE_UNIT_HEIGHT,
+        finesse::FinesseTracker,
+        game::{DroppedOrNothing, Game},
+        mode::{GameMode, ModeKind, ModeProgress},
+        model::{score::ScoringAction, tetrimino::Tetrimino},
+        stats::PlayStats,
+    },
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const COUNTDOWN_SEC: u64 = 3;
+/// ポーズから戻るときに, 盤面を見せて待つ秒数.
+const RESUME_COUNTDOWN_SEC: u64 = 2;
+const BLOCK_LENGTH: f32 = 32.;
+
+const HUD_FONT_SIZE: f32 = 36.;
+const HUD_LINE_HEIGHT: f32 = 2. * HUD_FONT_SIZE;
+const HEADLINE_FONT_SIZE: f32 = 96.;
+const STATS_FONT_SIZE: f32 = 24.;
+
+/// 置いたブロックが消えるモードで, ラインを消したときに盤面を見せる時間.
+const REVEAL_DURATION: Duration = Duration::from_secs(1);
+
+/// 続きから遊べるモードで, 中断したプレイを自動で保存する間隔.
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// # モードを遊ぶシーン
+///
+/// 盤面の操作と描画は共通で, 終わり方や難易度の変化は `GameMode` に任せる.
+pub struct PlayState {
+    kind: ModeKind,
+    /// ミノの出現順を決めたシード. 同じ順番でやり直すときに使う.
+    seed: u64,
+    mode: Box<dyn GameMode>,
+    game: Game,
+    controller: GameController,
+    stats: PlayStats,
+    phase: Phase,
+    layout: FieldLayout,
+    /// 続きから遊べるモードのときだけ鳴る.
+    autosave: Option<Timer>,
+    /// ブロックが消えるモードで, この時刻までは盤面を全て見せる.
+    revealed_until: Duration,
+    shows_stats: bool,
+    /// ポーズメニューが上に積まれている間は, 盤面を隠す.
+    paused: bool,
+    finesse: FinesseTracker,
+    /// ライン消去の演出の間は, ミノを操作できない.
+    removing: Option<RemovingLineAnimation>,
+    windbreak_particles: AnimationProperties<DroppingWindbreakParticle>,
+}
+
+enum Phase {
+    /// `resuming` はポーズから戻るときのカウントダウンで, 盤面を見せたまま数える.
+    Countdown {
+        timer: Timer,
+        remaining: u64,
+        resuming: bool,
+    },
+    Playing,
+    /// 結果画面が上に積まれ, もう動かない.
+    Finished,
+}
+
+impl Scene for PlayState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+
+    fn on_pause(&mut self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        // 結果画面の下では, 終わったときの盤面をそのまま見せる
+        if let Phase::Finished = self.phase {
+            return Ok(());
+        }
+
+        self.paused = true;
+        asset.audio.pause_bgm();
+
+        // ポーズメニューからタイトルへ戻っても続きから遊べるように, ここで保存しておく
+        if self.kind.is_resumable() {
+            save_session(ctx, self)?;
+        }
+
+        Ok(())
+    }
+
+    fn on_resume(&mut self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        self.paused = false;
+        self.phase = Phase::Countdown {
+            timer: Timer::repeat(Duration::from_secs(1), RESUME_COUNTDOWN_SEC as u32),
+            remaining: RESUME_COUNTDOWN_SEC,
+            resuming: true,
+        };
+
+        asset.audio.play_se(ctx, Se::CountdownTick)
+    }
+}
+
+pub fn init(ctx: &mut Context, asset: &mut Asset, kind: ModeKind) -> GameResult<PlayState> {
+    start(ctx, asset, kind, None, false)
+}
+
+/// 直前のプレイのシードが `last_seed` のときに, 設定に従ってすぐに始め直す.
+/// 中断したプレイは再開せず, 最初から遊ぶ.
+pub fn retry(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    kind: ModeKind,
+    last_seed: u64,
+) -> GameResult<PlayState> {
+    let settings = FileRetrySettingsRepository.load(ctx)?;
+    let mut state = start(ctx, asset, kind, settings.seed(last_seed), true)?;
+
+    if settings.skips_countdown {
+        asset.audio.play_bgm(ctx, Bgm::InGame)?;
+        asset.audio.play_se(ctx, Se::GameStart)?;
+        state.phase = Phase::Playing;
+    }
+
+    Ok(state)
+}
+
+/// `seed` が `None` のときは, モードで決まっていなければ新しい順番でミノが出現する.
+fn start(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    kind: ModeKind,
+    seed: Option<u64>,
+    is_retry: bool,
+) -> GameResult<PlayState> {
+    asset.audio.stop_bgm();
+    asset.audio.play_se(ctx, Se::CountdownTick)?;
+
+    let rule = kind.rule();
+    let seed = kind.seed().or(seed).unwrap_or_else(random);
+    let mut game = Game::with_seeded_rule(rule, seed);
+    let mut mode = kind.create(seed);
+    mode.setup(&mut game);
+
+    let mut stats = PlayStats::new();
+    if kind.is_resumable() && !is_retry {
+        if let Some(session) = FileSessionRepository.load(ctx, &kind.record_name())? {
+            session.restore(&mut game, &mut stats);
+        }
+    }
+
+    Ok(PlayState {
+        kind,
+        seed,
+        mode,
+        game,
+        controller: GameController::from_rule(&rule),
+        stats,
+        phase: Phase::Countdown {
+            timer: Timer::repeat(Duration::from_secs(1), COUNTDOWN_SEC as u32),
+            remaining: COUNTDOWN_SEC,
+            resuming: false,
+        },
+        layout: FieldLayout::new(
+            (
+                WINDOW_WIDTH / 8.,
+                WINDOW_HEIGHT / 2. - BLOCK_LENGTH * (FIELD_VISIBLE_UNIT_HEIGHT as f32 / 2.),
+            )
+                .into(),
+            BLOCK_LENGTH,
+        ),
+        autosave: kind
+            .is_resumable()
+            .then(|| Timer::infinite(AUTOSAVE_INTERVAL, AUTOSAVE_INTERVAL)),
+        revealed_until: Duration::ZERO,
+        shows_stats: false,
+        paused: false,
+        finesse: FinesseTracker::new(),
+        removing: None,
+        windbreak_particles: AnimationProperties::new(),
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: PlayState,
+    delta: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    if input_cache.has_pushed(&ControlCode::Retry) {
+        return Ok(Next::transit(Ticket::Retry {
+            kind: state.kind,
+            seed: state.seed,
+        }));
+    }
+
+    if input_cache.has_pushed(&ControlCode::ToggleStats) {
+        state.shows_stats = !state.shows_stats;
+    }
+
+    state.windbreak_particles.elapse(delta);
+
+    match state.phase {
+        Phase::Countdown {
+            ref mut timer,
+            ref mut remaining,
+            resuming,
+        } => {
+            timer.elapse(*delta);
+
+            if timer.consume_if_beep() {
+                *remaining -= 1;
+
+                if *remaining == 0 {
+                    if resuming {
+                        asset.audio.resume_bgm();
+                    } else {
+                        asset.audio.play_bgm(ctx, Bgm::InGame)?;
+                    }
+                    asset.audio.play_se(ctx, Se::GameStart)?;
+                    state.phase = Phase::Playing;
+                } else {
+                    asset.audio.play_se(ctx, Se::CountdownTick)?;
+                }
+            }
+        }
+        Phase::Playing => {
+            if input_cache.has_pushed(&ControlCode::Pause) {
+                let (kind, seed) = (state.kind, state.seed);
+
+                return Ok(Next::push(state, Ticket::Pause { kind, seed }));
+            }
+
+            if let Some(ref mut autosave) = state.autosave {
+                autosave.elapse(*delta);
+
+                if autosave.consume_if_beep() {
+                    save_session(ctx, &state)?;
+                }
+            }
+
+            if let Some(ref mut removing) = state.removing {
+                removing.elapse(delta);
+                if !removing.is_finished() {
+                    return Ok(Next::do_continue(state));
+                }
+                state.removing = None;
+            }
+
+            let cleared = match update_game(ctx, input_cache, asset, &mut state, delta)? {
+                ModeProgress::Playing => None,
+                ModeProgress::Cleared => Some(true),
+                ModeProgress::Failed => Some(false),
+            };
+            if let Some(cleared) = cleared {
+                let ticket = finish(ctx, asset, &mut state, cleared)?;
+
+                return Ok(Next::push(state, ticket));
+            }
+        }
+        Phase::Finished => (),
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+fn update_game(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: &mut PlayState,
+    delta: &Duration,
+) -> GameResult<ModeProgress> {
+    for _ in 0..GameController::count_pushed(input_cache) {
+        state.stats.count_input();
+    }
+
+    // 出現を待っている間の入力は, どのミノも動かさない
+    let positioning = match state.game.is_entering() {
+        true => 0,
+        false => GameController::count_positioning(input_cache),
+    };
+    let mut operations = state.controller.operate(&mut state.game, input_cache);
+    if operations.iter().any(|op| matches!(op, Operation::Held)) {
+        state.finesse.reset();
+    }
+    state.finesse.count_inputs(positioning);
+    let has_put = operations.iter().any(|op| matches!(op, Operation::Put(_)));
+    if !has_put {
+        if let DroppedOrNothing::Dropped(Some(removed_lines)) = state.game.elapse(*delta) {
+            operations.push(Operation::Put(removed_lines));
+        }
+    }
+
+    for operation in operations {
+        match operation {
+            Operation::Moved => asset.audio.play_se(ctx, Se::MinoMove)?,
+            Operation::Spun => asset.audio.play_se(ctx, Se::MinoSpin)?,
+            Operation::SoftDropped => {
+                asset.audio.play_se(ctx, Se::MinoSoftDrop)?;
+                state.finesse.count_soft_drop();
+            }
+            Operation::Held => (),
+            Operation::Put(removed_lines) => {
+                asset.audio.play_se(ctx, Se::MinoHardDrop)?;
+
+                // 大きなミノは, 何もない盤面での最小の入力数が求められない
+                let is_fault = state.finesse.judge(&state.game.board);
+                if is_fault && !state.game.rule.big {
+                    state.stats.count_finesse_fault();
+                }
+
+                add_windbreak_particles(state);
+
+                state.game.put_and_spawn();
+                if !removed_lines.is_empty() {
+                    asset.audio.play_se(ctx, Se::RemoveLine)?;
+                    state.removing = Some(RemovingLineAnimation::new(
+                        state.game.board.confirmed_field,
+                        removed_lines,
+                        state.game.board.width(),
+                    ));
+                    state.game.remove_lines();
+                    state.revealed_until = state.game.elapsed + REVEAL_DURATION;
+                }
+            }
+        }
+    }
+
+    let events = state.game.take_events();
+    for event in &events {
+        state.stats.receive(event);
+    }
+
+    Ok(state.mode.update(&mut state.game, &events))
+}
+
+/// 置いたミノの列ごとに, 一番上のマスから風切りを流す.
+fn add_windbreak_particles(state: &mut PlayState) {
+    let points = state.game.board.dropping_mino_points();
+    let top = points.iter().map(|p| p.y).min().unwrap_or(0).max(0) as usize;
+
+    points.iter().map(|p| p.x as usize).unique().for_each(|x| {
+        state
+            .windbreak_particles
+            .add(DroppingWindbreakParticle::new((x, top)))
+    });
+}
+
+fn save_session(ctx: &mut Context, state: &PlayState) -> GameResult {
+    let session = PlaySession::capture(&state.game, &state.stats);
+
+    FileSessionRepository.save(ctx, &state.kind.record_name(), &session)
+}
+
+/// 記録を保存し, 今までの記録の中での順位を付けた結果画面を用意する.
+fn finish(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    state: &mut PlayState,
+    cleared: bool,
+) -> GameResult<Ticket> {
+    asset.audio.stop_bgm();
+
+    let record = PlayRecord::new(
+        &state.kind.record_name(),
+        state.game.score,
+        state.game.removed_line_count,
+        state.game.elapsed,
+        state.stats.clone(),
+        cleared,
+        state.mode.result_title(cleared),
+    );
+    let records = FileRecordRepository.records(ctx, &record.mode)?;
+    let ranking = state.kind.ranking();
+    let rank = ranking.rank(&records, &record);
+    let best = ranking.best(&records).cloned();
+
+    state.phase = Phase::Finished;
+    FileRecordRepository.save(ctx, record.clone())?;
+
+    Ok(Ticket::ShowResults {
+        kind: state.kind,
+        seed: state.seed,
+        record: Box::new(record),
+        best: best.map(Box::new),
+        rank,
+    })
+}
+
+pub fn draw(ctx: &mut Context, state: &PlayState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    let layout = &state.layout;
+    let game = &state.game;
+
+    renderer::draw_field(ctx, asset, layout)?;
+    draw_hud(ctx, asset, state)?;
+    draw_headline(ctx, asset, state)?;
+    if state.shows_stats {
+        draw_stats(ctx, asset, state)?;
+    }
+
+    let shows_board = !state.paused
+        && !matches!(
+            state.phase,
+            Phase::Countdown {
+                resuming: false,
+                ..
+            }
+        );
+    if shows_board {
+        if game.rule.hold {
+            renderer::draw_hold_panel(ctx, asset, layout, game.hold_mino)?;
+        }
+        renderer::draw_next_panel(
+            ctx,
+            asset,
+            layout,
+            game.bag.peek(game.rule.next_amount).as_slice(),
+        )?;
+        // 大きなミノは, 同じ位置から2倍の大きさのブロックで描く
+        let mino_layout = &if game.rule.big {
+            layout.scaled(2.)
+        } else {
+            *layout
+        };
+
+        for particle in state.windbreak_particles.props() {
+            particle.draw(ctx, asset, mino_layout)?;
+        }
+
+        let fade = game.rule.fade.filter(|_| {
+            !matches!(state.phase, Phase::Finished) && state.revealed_until <= game.elapsed
+        });
+        match (&state.removing, fade) {
+            // 消えるラインは, 消す前の盤面の上で光らせてから潰す
+            (Some(removing), _) => {
+                renderer::draw_blocks(ctx, asset, mino_layout, &removing.field, &removing.lines)?;
+                removing.draw(ctx, mino_layout)?;
+            }
+            (None, Some(fade)) => renderer::draw_fading_minos(
+                ctx,
+                asset,
+                mino_layout,
+                &game.board,
+                !game.is_entering(),
+                game.elapsed,
+                fade,
+            )?,
+            (None, None) => {
+                renderer::draw_minos(ctx, asset, mino_layout, &game.board, !game.is_entering())?
+            }
+        }
+
+        if game.rule.ghost
+            && matches!(state.phase, Phase::Playing)
+            && !game.is_entering()
+            && state.removing.is_none()
+        {
+            renderer::draw_ghost(ctx, mino_layout, &game.board)?;
+        }
+        if let Some(placement) = state.mode.guide(game) {
+            renderer::draw_guide(ctx, mino_layout, &placement)?;
+        }
+    }
+
+    if let Phase::Countdown { remaining, .. } = state.phase {
+        renderer::draw_count_down(ctx, asset, remaining)?;
+    }
+
+    Ok(())
+}
+
+fn draw_hud(ctx: &mut Context, asset: &Asset, state: &PlayState) -> GameResult {
+    let x = state.layout.next_origin().x + 4. * state.layout.block_length;
+    let y = state.layout.origin.y + state.layout.field_height() / 12.;
+
+    for (idx, (name, value)) in state.mode.hud(&state.game).iter().enumerate() {
+        let text = graphics::Text::new(
+            graphics::TextFragment::new(format!("{0: <7}: {1: >9}", name, value))
+                .font(asset.font.vt323)
+                .scale(PxScale::from(HUD_FONT_SIZE)),
+        );
+
+        graphics::draw(
+            ctx,
+            &text,
+            DrawParam::default().dest([x, y + (idx as f32) * HUD_LINE_HEIGHT]),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// HUDの下に, プレイの統計を並べる.
+fn draw_stats(ctx: &mut Context, asset: &Asset, state: &PlayState) -> GameResult {
+    let stats = &state.stats;
+    let elapsed = &state.game.elapsed;
+    let analysis = BoardAnalysis::of(&state.game.board);
+
+    let clears = [
+        ("SINGLE", ScoringAction::Single),
+        ("DOUBLE", ScoringAction::Double),
+        ("TRIPLE", ScoringAction::Triple),
+        ("TETRIS", ScoringAction::Tetris),
+        ("T-SPIN", ScoringAction::TSpinSingle),
+        ("TSD", ScoringAction::TSpinDouble),
+        ("TST", ScoringAction::TSpinTriple),
+        ("PC", ScoringAction::PerfectClear),
+    ];
+    let distribution = Tetrimino::all()
+        .iter()
+        .map(|mino| format!("{:?}{}", mino, stats.mino_count(mino)))
+        .join(" ");
+
+    let lines = vec![
+        format!("{0: <9}: {1: >7}", "SCORE", state.game.score),
+        format!("{0: <9}: {1: >7}", "LINES", state.game.removed_line_count),
+        format!("{0: <9}: {1: >7}", "PIECES", stats.pieces),
+        format!("{0: <9}: {1: >7.2}", "PPS", stats.pps(elapsed)),
+        format!("{0: <9}: {1: >7.2}", "KPP", stats.kpp()),
+        format!("{0: <9}: {1: >7.2}", "APM", stats.apm(elapsed)),
+        format!("{0: <9}: {1: >7}", "MAX COMBO", stats.max_combo),
+        format!("{0: <9}: {1: >7}", "MAX B2B", stats.max_back_to_back),
+        format!("{0: <9}: {1: >7}", "FINESSE", stats.finesse_faults),
+        format!(
+            "{0: <9}: {1: >7}",
+            "HEIGHT",
+            format!("{}/{}", analysis.max_height(), analysis.aggregate_height())
+        ),
+        format!(
+            "{0: <9}: {1: >7}",
+            "HOLES",
+            format!("{}/{}", analysis.holes, analysis.covered_cells)
+        ),
+        format!("{0: <9}: {1: >7}", "BUMPINESS", analysis.bumpiness),
+        format!(
+            "{0: <9}: {1: >7}",
+            "TRANS",
+            format!(
+                "{}/{}",
+                analysis.row_transitions, analysis.column_transitions
+            )
+        ),
+        format!(
+            "{0: <9}: {1: >7}",
+            "WELL",
+            analysis
+                .deepest_well()
+                .map(|x| format!("{}({})", x, analysis.well_depths[x]))
+                .unwrap_or_else(|| "-".to_owned())
+        ),
+        format!("{0: <9}: {1: >7}", "T-SLOTS", analysis.t_slots.len()),
+        format!(
+            "{0: <9}: {1: >7}",
+            "TETRIS",
+            if analysis.tetris_ready { "READY" } else { "-" }
+        ),
+    ]
+    .into_iter()
+    .chain(
+        clears
+            .iter()
+            .map(|(name, action)| format!("{0: <9}: {1: >7}", name, stats.clear_count(action))),
+    )
+    .chain(std::iter::once(distribution));
+
+    let x = state.layout.next_origin().x + 4. * state.layout.block_length;
+    let y = state.layout.origin.y
+        + state.layout.field_height() / 12.
+        + (state.mode.hud(&state.game).len() as f32) * HUD_LINE_HEIGHT;
+
+    for (idx, line) in lines.enumerate() {
+        let text = graphics::Text::new(
+            graphics::TextFragment::new(line)
+                .font(asset.font.vt323)
+                .scale(PxScale::from(STATS_FONT_SIZE)),
+        );
+
+        graphics::draw(
+            ctx,
+            &text,
+            DrawParam::default().dest([x, y + (idx as f32) * STATS_FONT_SIZE]),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn draw_headline(ctx: &mut Context, asset: &Asset, state: &PlayState) -> GameResult {
+    if let Some(headline) = state.mode.headline(&state.game) {
+        let text = graphics::Text::new(
+            graphics::TextFragment::new(headline)
+                .font(asset.font.vt323)
+                .scale(PxScale::from(HEADLINE_FONT_SIZE)),
+        );
+        let x = state.layout.next_origin().x + 4. * state.layout.block_length;
+        let y = state.layout.origin.y + state.layout.field_height() * 2. / 3.;
+
+        graphics::draw(ctx, &text, DrawParam::default().dest([x, y]))?;
+    }
+
+    Ok(())
+}
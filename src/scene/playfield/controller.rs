@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use crate::{
+    model::{control_code::ControlCode, input_cache::InputCache},
+    tetris::{board::RemovedLines, game::Game, rule::Rule},
+};
+
+/// ミノを操作するための入力. 押した回数を統計に数えるために使う.
+const OPERATING_CODES: [ControlCode; 7] = [
+    ControlCode::MoveLeft,
+    ControlCode::MoveRight,
+    ControlCode::SoftDrop,
+    ControlCode::HardDrop,
+    ControlCode::RotateCounterclockwise,
+    ControlCode::RotateClockwise,
+    ControlCode::SwapHold,
+];
+
+/// ミノの位置と向きを変えるための入力. フィネスの判定に使う.
+const POSITIONING_CODES: [ControlCode; 4] = [
+    ControlCode::MoveLeft,
+    ControlCode::MoveRight,
+    ControlCode::RotateCounterclockwise,
+    ControlCode::RotateClockwise,
+];
+
+/// # 操作の結果
+///
+/// 効果音や演出を鳴らすために, 実際に成功した操作だけが返される.
+#[derive(Debug)]
+pub enum Operation {
+    Moved,
+    Spun,
+    Held,
+    SoftDropped,
+    Put(RemovedLines),
+}
+
+/// # ゲームの操作
+///
+/// 入力の状態を, 押しっぱなしのリピートを考慮した `Game` の操作に変換する.
+pub struct GameController {
+    das: Duration,
+    arr: Duration,
+}
+
+impl GameController {
+    pub fn new(das: Duration, arr: Duration) -> GameController {
+        GameController { das, arr }
+    }
+
+    pub fn from_rule(rule: &Rule) -> GameController {
+        GameController::new(rule.das, rule.arr)
+    }
+
+    pub fn operate(&self, game: &mut Game, input_cache: &mut InputCache) -> Vec<Operation> {
+        let mut operations = Vec::new();
+        if game.is_entering() {
+            return operations;
+        }
+
+        if input_cache.has_pushed(&ControlCode::SwapHold) && game.try_swap_hold() {
+            operations.push(Operation::Held);
+        }
+
+        if self.repeats(input_cache, &ControlCode::MoveLeft) && game.move_left() {
+            operations.push(Operation::Moved);
+        }
+        if self.repeats(input_cache, &ControlCode::MoveRight) && game.move_right() {
+            operations.push(Operation::Moved);
+        }
+
+        if input_cache.has_pushed(&ControlCode::RotateCounterclockwise) && game.spin_left() {
+            operations.push(Operation::Spun);
+        }
+        if input_cache.has_pushed(&ControlCode::RotateClockwise) && game.spin_right() {
+            operations.push(Operation::Spun);
+        }
+
+        if game.rule.hard_drop && input_cache.has_pushed(&ControlCode::HardDrop) {
+            operations.push(Operation::Put(game.hard_drop()));
+        } else if self.repeats(input_cache, &ControlCode::SoftDrop)
+            && !game.board.dropping_mino_is_on_ground()
+        {
+            match game.soft_drop() {
+                Some(removed_lines) => operations.push(Operation::Put(removed_lines)),
+                None => operations.push(Operation::SoftDropped),
+            }
+        }
+
+        operations
+    }
+
+    /// この更新で新たに押された, ミノを操作するための入力の数.
+    pub fn count_pushed(input_cache: &InputCache) -> usize {
+        OPERATING_CODES
+            .iter()
+            .filter(|code| input_cache.has_pushed(code))
+            .count()
+    }
+
+    /// この更新で新たに押された, ミノを動かしたり回したりするための入力の数.
+    pub fn count_positioning(input_cache: &InputCache) -> usize {
+        POSITIONING_CODES
+            .iter()
+            .filter(|code| input_cache.has_pushed(code))
+            .count()
+    }
+
+    fn repeats(&self, input_cache: &mut InputCache, code: &ControlCode) -> bool {
+        input_cache.has_pushed(code)
+            || input_cache.handle_hold_if_unhandled_yet_after(code, &self.das)
+            || input_cache.handle_hold_if_handled_before(code, &self.arr)
+    }
+}
+
+impl Default for GameController {
+    fn default() -> Self {
+        GameController::from_rule(&Rule::guideline())
+    }
+}
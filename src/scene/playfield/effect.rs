@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use ggez::{
+    graphics,
+    graphics::{DrawMode, DrawParam, Rect},
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::Asset,
+    scene::{animation_property::AnimationProperty, playfield::renderer::FieldLayout},
+    tetris::board::Field,
+};
+
+const REMOVING_LINE_ANIM_PHASE_1: Duration = Duration::from_millis(400);
+const REMOVING_LINE_ANIM_PHASE_2: Duration = Duration::from_millis(550);
+
+const DROPPING_WINDBREAK_ANIM_END: Duration = Duration::from_millis(240);
+
+/// # ライン消去の演出
+///
+/// 消えるラインを白く光らせてから, 上下から潰すように消す.
+/// 演出の間は, ラインを消す前の盤面を見せる.
+pub struct RemovingLineAnimation {
+    /// ラインを消す前の, 置かれたブロック.
+    pub field: Field,
+    pub lines: Vec<usize>,
+    /// 盤面の横のマス数.
+    width: usize,
+    elapsed: Duration,
+}
+
+impl RemovingLineAnimation {
+    pub fn new(field: Field, lines: Vec<usize>, width: usize) -> RemovingLineAnimation {
+        RemovingLineAnimation {
+            field,
+            lines,
+            width,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn elapse(&mut self, delta: &Duration) {
+        self.elapsed += *delta;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed > REMOVING_LINE_ANIM_PHASE_2
+    }
+
+    pub fn draw(&self, ctx: &mut Context, layout: &FieldLayout) -> GameResult {
+        let elapsed = self.elapsed;
+        let width = (self.width as f32) * layout.block_length;
+
+        let rects: Vec<_> = if elapsed < REMOVING_LINE_ANIM_PHASE_1 {
+            let alpha = elapsed.as_secs_f32() / REMOVING_LINE_ANIM_PHASE_1.as_secs_f32();
+
+            self.lines
+                .iter()
+                .map(|&line| {
+                    let rect = Rect::new(
+                        layout.origin.x,
+                        layout.line_y(line),
+                        width,
+                        layout.block_length,
+                    );
+
+                    (rect, alpha)
+                })
+                .collect()
+        } else if elapsed < REMOVING_LINE_ANIM_PHASE_2 {
+            let percentage = (elapsed - REMOVING_LINE_ANIM_PHASE_1).as_secs_f32()
+                / (REMOVING_LINE_ANIM_PHASE_2 - REMOVING_LINE_ANIM_PHASE_1).as_secs_f32();
+
+            self.lines
+                .iter()
+                .map(|&line| {
+                    let rect = Rect::new(
+                        layout.origin.x,
+                        layout.line_y(line) + layout.block_length * percentage / 2.,
+                        width,
+                        layout.block_length * (1. - percentage),
+                    );
+
+                    (rect, percentage)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        for (rect, alpha) in rects {
+            let mesh = graphics::Mesh::new_rectangle(
+                ctx,
+                DrawMode::fill(),
+                rect,
+                graphics::Color::new(1., 1., 1., alpha),
+            )?;
+            graphics::draw(ctx, &mesh, DrawParam::default())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// # ハードドロップの風切り
+///
+/// 置いたミノの列ごとに, 一番上のマスから上へ流れて消える.
+pub struct DroppingWindbreakParticle {
+    /// 流れ始めるマス. 隠れている行も数える.
+    cell: (usize, usize),
+    elapsed: Duration,
+}
+
+impl DroppingWindbreakParticle {
+    pub fn new(cell: (usize, usize)) -> DroppingWindbreakParticle {
+        DroppingWindbreakParticle {
+            cell,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut Context, asset: &Asset, layout: &FieldLayout) -> GameResult {
+        let percentage = self.elapsed.as_secs_f32() / DROPPING_WINDBREAK_ANIM_END.as_secs_f32();
+        let (x, y) = self.cell;
+        let x = layout.origin.x + (x as f32) * layout.block_length;
+        let y = layout.line_y(y) - 6. * layout.block_length * percentage;
+
+        graphics::draw(
+            ctx,
+            &asset.image.dropping_windbreak_particle,
+            DrawParam::default()
+                .offset([0., 1.])
+                .dest([x, y])
+                .color(graphics::Color::new(1., 1., 1., 0.8 - percentage)),
+        )
+    }
+}
+
+impl AnimationProperty for DroppingWindbreakParticle {
+    fn duration(&self) -> &Duration {
+        &self.elapsed
+    }
+
+    fn elapse(mut self, delta: &Duration) -> Self {
+        self.elapsed += *delta;
+
+        self
+    }
+
+    fn is_active(&self) -> bool {
+        self.elapsed < DROPPING_WINDBREAK_ANIM_END
+    }
+}
@@ -0,0 +1,593 @@
+use std::time::Duration;
+
+use ggez::{
+    graphics,
+    graphics::{DrawMode, DrawParam, PxScale, Rect},
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::{color::Color as AssetColor, Asset},
+    model::xytuple::F32XYTuple,
+    tetris::{
+        board::{Board, Field, FIELD_UNIT_HEIGHT, FIELD_UNIT_WIDTH, FIELD_VISIBLE_UNIT_HEIGHT},
+        model::tetrimino::{MinoBlock, MinoRotation, Tetrimino},
+        opener::Placement,
+    },
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const PANEL_FONT_SIZE: f32 = 24.;
+const LABEL_FONT_SIZE: f32 = 32.;
+const RESULT_FONT_SIZE: f32 = 72.;
+const HIDDEN_UNIT_HEIGHT: usize = FIELD_UNIT_HEIGHT - FIELD_VISIBLE_UNIT_HEIGHT;
+
+/// # フィールドの配置
+///
+/// フィールドの左上の座標とブロックの大きさから, 盤面と周りのパネルの位置を決める.
+#[derive(new, Copy, Clone, Debug)]
+pub struct FieldLayout {
+    /// 見えている一番上の行の左上の座標.
+    pub origin: F32XYTuple,
+    pub block_length: f32,
+}
+
+impl FieldLayout {
+    /// 同じ位置で, ブロックを `scale` 倍の大きさにした配置.
+    pub fn scaled(&self, scale: f32) -> FieldLayout {
+        FieldLayout::new(self.origin, self.block_length * scale)
+    }
+
+    /// 画面を左右に2分割したときの, `screen_index` 番目の画面の中央に置いたフィールド.
+    pub fn split_screen(screen_index: usize, block_length: f32) -> FieldLayout {
+        let screen_width = WINDOW_WIDTH / 2.;
+        let field_width = block_length * (FIELD_UNIT_WIDTH as f32);
+
+        let x = screen_width * (screen_index as f32) + (screen_width - field_width) / 2.;
+        let y = WINDOW_HEIGHT / 2. - block_length * (FIELD_VISIBLE_UNIT_HEIGHT as f32 / 2.);
+
+        FieldLayout::new((x, y).into(), block_length)
+    }
+
+    pub fn half_block_length(&self) -> f32 {
+        self.block_length / 2.
+    }
+
+    pub fn field_width(&self) -> f32 {
+        self.block_length * (FIELD_UNIT_WIDTH as f32)
+    }
+
+    pub fn field_height(&self) -> f32 {
+        self.block_length * (FIELD_UNIT_HEIGHT as f32)
+    }
+
+    fn side_panel_width(&self) -> f32 {
+        4. * self.half_block_length()
+    }
+
+    fn mino_space_height(&self) -> f32 {
+        6. * self.half_block_length()
+    }
+
+    pub fn hold_origin(&self) -> F32XYTuple {
+        (
+            self.origin.x - self.block_length - self.side_panel_width(),
+            self.origin.y,
+        )
+            .into()
+    }
+
+    pub fn next_origin(&self) -> F32XYTuple {
+        (
+            self.origin.x + self.field_width() + self.block_length,
+            self.origin.y,
+        )
+            .into()
+    }
+
+    /// フィールドの `y` 行目の上端の座標. 隠れている行も数える.
+    pub fn line_y(&self, y: usize) -> f32 {
+        self.origin.y + (y as f32 - HIDDEN_UNIT_HEIGHT as f32) * self.block_length
+    }
+
+    /// 画面上の座標にある, 見えている行のマス.
+    pub fn cell_at(&self, x: f32, y: f32) -> Option<(usize, usize)> {
+        let cell_x = ((x - self.origin.x) / self.block_length).floor();
+        let cell_y = ((y - self.origin.y) / self.block_length).floor();
+        if cell_x < 0. || cell_y < 0. {
+            return None;
+        }
+
+        let cell_x = cell_x as usize;
+        let cell_y = cell_y as usize + HIDDEN_UNIT_HEIGHT;
+        (cell_x < FIELD_UNIT_WIDTH && cell_y < FIELD_UNIT_HEIGHT).then_some((cell_x, cell_y))
+    }
+
+    fn block_scale(&self, image: &graphics::Image) -> f32 {
+        self.block_length / f32::from(image.width())
+    }
+}
+
+pub fn draw_field(ctx: &mut Context, asset: &Asset, layout: &FieldLayout) -> GameResult {
+    let (origin_x, origin_y) = layout.origin.into();
+    let block_length = layout.block_length;
+    let half = layout.half_block_length();
+    let width = layout.field_width();
+    let height = layout.field_height();
+
+    let background = graphics::Mesh::new_rectangle(
+        ctx,
+        DrawMode::fill(),
+        Rect::new(origin_x, origin_y - block_length, width, height),
+        asset.color.panel,
+    )?;
+    graphics::draw(ctx, &background, DrawParam::default())?;
+
+    for x in (0..=FIELD_UNIT_WIDTH).map(|x| origin_x + (x as f32) * block_length) {
+        let line = graphics::Mesh::new_line(
+            ctx,
+            &[
+                [x, origin_y - block_length],
+                [x, origin_y - block_length + height],
+            ],
+            1.,
+            asset.color.grid_line,
+        )?;
+        graphics::draw(ctx, &line, DrawParam::default())?;
+    }
+
+    for y in
+        (-1..=(FIELD_VISIBLE_UNIT_HEIGHT as isize)).map(|y| origin_y + (y as f32) * block_length)
+    {
+        let line = graphics::Mesh::new_line(
+            ctx,
+            &[[origin_x, y], [origin_x + width, y]],
+            1.,
+            asset.color.grid_line,
+        )?;
+        graphics::draw(ctx, &line, DrawParam::default())?;
+    }
+
+    let frame = graphics::Mesh::new_rectangle(
+        ctx,
+        DrawMode::stroke(half),
+        Rect::new(
+            origin_x - half / 2.,
+            origin_y - half * 3. / 2.,
+            width + half - 1.0,
+            height - 1.0,
+        ),
+        asset.color.frame,
+    )?;
+    graphics::draw(ctx, &frame, DrawParam::default())?;
+
+    Ok(())
+}
+
+/// 盤面のブロックを描画する.
+pub fn draw_minos(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    layout: &FieldLayout,
+    board: &Board,
+    shows_dropping_mino: bool,
+) -> GameResult {
+    let field = if shows_dropping_mino {
+        board.field()
+    } else {
+        board.confirmed_field
+    };
+
+    draw_blocks(ctx, asset, layout, &field, &[])
+}
+
+/// `Board` を持たない, 受け取っただけのフィールドを描画する.
+/// `hidden_lines` の行は消去の演出のために描画しない.
+pub fn draw_blocks(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    layout: &FieldLayout,
+    field: &Field,
+    hidden_lines: &[usize],
+) -> GameResult {
+    for (y, line) in field.iter().enumerate() {
+        if hidden_lines.contains(&y) {
+            continue;
+        }
+
+        for (x, entity) in line.iter().enumerate() {
+            if let Some(block) = entity.block() {
+                draw_block(ctx, asset, layout, x, y, &block, 1.)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 置かれたブロックは `fade` をかけて薄くなって消え, 落下中のミノだけがはっきり見える.
+/// `fade` が0のときは, 置いた瞬間に見えなくなる.
+pub fn draw_fading_minos(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    layout: &FieldLayout,
+    board: &Board,
+    shows_dropping_mino: bool,
+    now: Duration,
+    fade: Duration,
+) -> GameResult {
+    for (y, line) in board.confirmed_field.iter().enumerate() {
+        for (x, entity) in line.iter().enumerate() {
+            if let Some(block) = entity.block() {
+                let since_locked = now.saturating_sub(board.locked_at[y][x]);
+                let alpha = if fade.is_zero() {
+                    0.
+                } else {
+                    1. - since_locked.as_secs_f32() / fade.as_secs_f32()
+                };
+
+                if 0. < alpha {
+                    draw_block(ctx, asset, layout, x, y, &block, alpha.min(1.))?;
+                }
+            }
+        }
+    }
+
+    if shows_dropping_mino {
+        let block = board.dropping.block();
+        for p in board.dropping_mino_points() {
+            draw_block(ctx, asset, layout, p.x as usize, p.y as usize, &block, 1.)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn draw_block(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    layout: &FieldLayout,
+    x: usize,
+    y: usize,
+    block: &MinoBlock,
+    alpha: f32,
+) -> GameResult {
+    let img = asset.image.mino_block(ctx, block)?;
+    let scale = layout.block_scale(img);
+    let dest_x = layout.origin.x + (x as f32) * layout.block_length;
+
+    let param = if y < HIDDEN_UNIT_HEIGHT {
+        // 隠れている行は下半分だけを見せる
+        DrawParam::default()
+            .src(Rect::new(0., 0.5, 1., 0.5))
+            .dest([dest_x, layout.origin.y - layout.half_block_length()])
+    } else {
+        DrawParam::default().dest([dest_x, layout.line_y(y)])
+    };
+
+    graphics::draw(
+        ctx,
+        img,
+        param
+            .scale([scale, scale])
+            .color(graphics::Color::new(1., 1., 1., alpha)),
+    )
+}
+
+pub fn draw_ghost(ctx: &mut Context, layout: &FieldLayout, board: &Board) -> GameResult {
+    let field = board.field();
+    let color = AssetColor::block(&board.dropping.block());
+    let color = graphics::Color::from([color.r, color.g, color.b, 0.85]);
+
+    for prediction in board.calc_dropping_mino_prediction() {
+        let entity = field
+            .get(prediction.y as usize)
+            .and_then(|line| line.get(prediction.x as usize))
+            .unwrap();
+
+        if entity.is_air() {
+            draw_outline(
+                ctx,
+                layout,
+                prediction.x as usize,
+                prediction.y as usize,
+                color,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 定型の練習で, 次にミノを置くべき場所を枠だけで描画する.
+pub fn draw_guide(ctx: &mut Context, layout: &FieldLayout, placement: &Placement) -> GameResult {
+    let color = AssetColor::block(&placement.mino.block());
+    let color = graphics::Color::from([color.r, color.g, color.b, 0.5]);
+
+    for &(x, y) in &placement.cells {
+        draw_outline(ctx, layout, x, y, color)?;
+    }
+
+    Ok(())
+}
+
+/// エディタで選んでいるマスを枠で描画する.
+pub fn draw_cell_cursor(ctx: &mut Context, layout: &FieldLayout, x: usize, y: usize) -> GameResult {
+    draw_outline(ctx, layout, x, y, graphics::WHITE)
+}
+
+fn draw_outline(
+    ctx: &mut Context,
+    layout: &FieldLayout,
+    x: usize,
+    y: usize,
+    color: graphics::Color,
+) -> GameResult {
+    let padding = layout.block_length / 10.;
+    let square = graphics::Mesh::new_rectangle(
+        ctx,
+        DrawMode::stroke(2.),
+        Rect::new(
+            layout.origin.x + (x as f32) * layout.block_length + padding,
+            layout.line_y(y) + padding,
+            layout.block_length - 2. * padding,
+            layout.block_length - 2. * padding,
+        ),
+        color,
+    )?;
+
+    graphics::draw(ctx, &square, DrawParam::default())
+}
+
+pub fn draw_hold_panel(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    layout: &FieldLayout,
+    held: Option<Tetrimino>,
+) -> GameResult {
+    let origin = layout.hold_origin();
+    draw_panel_header(ctx, asset, layout, origin, "HOLD")?;
+
+    let bottom = origin.y + layout.mino_space_height();
+    let line = graphics::Mesh::new_line(
+        ctx,
+        &[
+            [origin.x, bottom],
+            [origin.x + layout.side_panel_width(), bottom],
+        ],
+        1.,
+        asset.color.separator,
+    )?;
+    graphics::draw(ctx, &line, DrawParam::default())?;
+
+    if let Some(mino) = held {
+        draw_mini_mino(ctx, asset, layout, &mino, origin)?;
+    }
+
+    Ok(())
+}
+
+pub fn draw_next_panel(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    layout: &FieldLayout,
+    minos: &[Tetrimino],
+) -> GameResult {
+    let origin = layout.next_origin();
+    draw_panel_header(ctx, asset, layout, origin, "NEXT")?;
+
+    for (idx, mino) in minos.iter().enumerate() {
+        let y = origin.y + (idx as f32) * layout.mino_space_height();
+        draw_mini_mino(ctx, asset, layout, mino, (origin.x, y).into())?;
+
+        let sep_y = y + layout.mino_space_height();
+        let sep = graphics::Mesh::new_line(
+            ctx,
+            &[
+                [origin.x, sep_y],
+                [origin.x + layout.side_panel_width(), sep_y],
+            ],
+            1.,
+            asset.color.separator,
+        )?;
+        graphics::draw(ctx, &sep, DrawParam::default())?;
+    }
+
+    Ok(())
+}
+
+/// フィールドの左に, せり上がりを待っているおじゃまラインの数を縦のゲージで描画する.
+pub fn draw_garbage_meter(ctx: &mut Context, layout: &FieldLayout, pending: usize) -> GameResult {
+    if pending == 0 {
+        return Ok(());
+    }
+
+    let lines = pending.min(FIELD_VISIBLE_UNIT_HEIGHT) as f32;
+    let width = layout.half_block_length() / 2.;
+    let bottom = layout.origin.y + (FIELD_VISIBLE_UNIT_HEIGHT as f32) * layout.block_length;
+    let height = lines * layout.block_length;
+
+    let meter = graphics::Mesh::new_rectangle(
+        ctx,
+        DrawMode::fill(),
+        Rect::new(
+            layout.origin.x - layout.half_block_length() - width,
+            bottom - height,
+            width,
+            height,
+        ),
+        graphics::Color::from_rgb(224, 48, 48),
+    )?;
+    graphics::draw(ctx, &meter, DrawParam::default())?;
+
+    Ok(())
+}
+
+pub fn draw_count_down(ctx: &mut Context, asset: &Asset, sec: u64) -> GameResult {
+    let rect = graphics::Mesh::new_rectangle(
+        ctx,
+        DrawMode::fill(),
+        Rect::new(0., 0., WINDOW_WIDTH, WINDOW_HEIGHT),
+        graphics::Color::new(0., 0., 0., 0.9),
+    )?;
+    graphics::draw(ctx, &rect, DrawParam::default())?;
+
+    let text = graphics::Text::new(
+        graphics::TextFragment::new(sec.to_string())
+            .font(asset.font.vt323)
+            .scale(PxScale::from(200.)),
+    );
+    graphics::draw(
+        ctx,
+        &text,
+        DrawParam::default().dest([
+            WINDOW_WIDTH / 2. - text.width(ctx) / 2.,
+            WINDOW_HEIGHT / 2. - text.height(ctx) / 2.,
+        ]),
+    )?;
+
+    Ok(())
+}
+
+/// フィールドの上に, 誰の盤面かを示すラベルを描画する.
+pub fn draw_label(
+    ctx: &mut Context,
+    asset: &Asset,
+    layout: &FieldLayout,
+    label: &str,
+) -> GameResult {
+    let text = graphics::Text::new(
+        graphics::TextFragment::new(label)
+            .font(asset.font.vt323)
+            .scale(PxScale::from(LABEL_FONT_SIZE)),
+    );
+    graphics::draw(
+        ctx,
+        &text,
+        DrawParam::default().dest([
+            layout.origin.x,
+            layout.origin.y - 2. * layout.block_length - LABEL_FONT_SIZE,
+        ]),
+    )?;
+
+    Ok(())
+}
+
+/// 画面を暗くして, 勝敗などの結果と操作の案内を描画する.
+pub fn draw_result(ctx: &mut Context, asset: &Asset, text: &str, guides: &[&str]) -> GameResult {
+    let rect = graphics::Mesh::new_rectangle(
+        ctx,
+        DrawMode::fill(),
+        Rect::new(0., 0., WINDOW_WIDTH, WINDOW_HEIGHT),
+        graphics::Color::new(0., 0., 0., 0.6),
+    )?;
+    graphics::draw(ctx, &rect, DrawParam::default())?;
+
+    let text = graphics::Text::new(
+        graphics::TextFragment::new(text)
+            .font(asset.font.vt323)
+            .scale(PxScale::from(RESULT_FONT_SIZE)),
+    );
+    let y = WINDOW_HEIGHT / 2. - text.height(ctx) / 2.;
+    graphics::draw(
+        ctx,
+        &text,
+        DrawParam::default().dest([WINDOW_WIDTH / 2. - text.width(ctx) / 2., y]),
+    )?;
+
+    for (idx, guide) in guides.iter().enumerate() {
+        let guide = graphics::Text::new(
+            graphics::TextFragment::new(*guide)
+                .font(asset.font.vt323)
+                .scale(PxScale::from(LABEL_FONT_SIZE)),
+        );
+        graphics::draw(
+            ctx,
+            &guide,
+            DrawParam::default().dest([
+                WINDOW_WIDTH / 2. - guide.width(ctx) / 2.,
+                y + RESULT_FONT_SIZE + (idx + 1) as f32 * LABEL_FONT_SIZE,
+            ]),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn draw_panel_header(
+    ctx: &mut Context,
+    asset: &Asset,
+    layout: &FieldLayout,
+    origin: F32XYTuple,
+    label: &str,
+) -> GameResult {
+    let text = graphics::Text::new(
+        graphics::TextFragment::new(label)
+            .font(asset.font.vt323)
+            .scale(PxScale::from(PANEL_FONT_SIZE)),
+    );
+    graphics::draw(
+        ctx,
+        &text,
+        DrawParam::default().dest([origin.x, origin.y - PANEL_FONT_SIZE]),
+    )?;
+
+    let line = graphics::Mesh::new_line(
+        ctx,
+        &[
+            [origin.x, origin.y],
+            [origin.x + layout.side_panel_width(), origin.y],
+        ],
+        2.,
+        asset.color.separator,
+    )?;
+    graphics::draw(ctx, &line, DrawParam::default())?;
+
+    Ok(())
+}
+
+/// サイドパネルの1マス分の枠の中に, 半分の大きさのミノを描画する.
+fn draw_mini_mino(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    layout: &FieldLayout,
+    mino: &Tetrimino,
+    space_origin: F32XYTuple,
+) -> GameResult {
+    let half = layout.half_block_length();
+    let x = space_origin.x
+        + match mino {
+            Tetrimino::O => half,
+            _ => 0.,
+        };
+    let y = space_origin.y
+        + 2. * half
+        + match mino {
+            Tetrimino::I => -half,
+            _ => 0.,
+        };
+
+    let shapes = mino.shapes();
+    let shape = shapes.get(&MinoRotation::Clockwise).unwrap();
+
+    for (dy, line) in shape.iter().enumerate() {
+        for (dx, &exists) in line.iter().enumerate() {
+            if exists {
+                let img = asset.image.mino_block(ctx, &mino.block())?;
+                let scale = layout.block_scale(img) / 2.;
+
+                graphics::draw(
+                    ctx,
+                    img,
+                    DrawParam::default()
+                        .dest([x + half * (dx as f32), y + half * (dy as f32)])
+                        .scale([scale, scale]),
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,267 @@
+use std::time::Duration;
+
+use ggez::{
+    graphics,
+    graphics::{DrawParam, PxScale},
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::{audio::Se, Asset},
+    infra::repo::file_puzzle_repository::FilePuzzleRepository,
+    model::{
+        control_code::ControlCode,
+        input_cache::InputCache,
+        repo::puzzle_repository::{PuzzleRepository, LOCAL_PLAYER},
+    },
+    scene::{
+        playfield::{
+            controller::{GameController, Operation},
+            renderer,
+            renderer::FieldLayout,
+        },
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+    },
+    tetris::{
+        board::FIELD_VISIBLE_UNIT_HEIGHT,
+        game::{DroppedOrNothing, Game},
+        mode::{puzzle::PuzzleMode, GameMode, ModeProgress},
+        puzzle::PuzzlePack,
+    },
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const BLOCK_LENGTH: f32 = 32.;
+const HUD_FONT_SIZE: f32 = 36.;
+const HUD_LINE_HEIGHT: f32 = 2. * HUD_FONT_SIZE;
+
+/// # パズルを解くシーン
+///
+/// 目標を達成すると解いたことを記録し, 同じパックの次のパズルに進める.
+pub struct PuzzleState {
+    pack: PuzzlePack,
+    index: usize,
+    mode: PuzzleMode,
+    game: Game,
+    controller: GameController,
+    phase: Phase,
+    layout: FieldLayout,
+}
+
+enum Phase {
+    Playing,
+    Finished { solved: bool },
+}
+
+impl Scene for PuzzleState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
+pub fn init(
+    _: &mut Context,
+    _: &mut Asset,
+    pack: PuzzlePack,
+    index: usize,
+) -> GameResult<PuzzleState> {
+    let puzzle = &pack.puzzles[index];
+    let game = puzzle.create_game();
+    let mode = PuzzleMode::new(puzzle.goal);
+
+    Ok(PuzzleState {
+        pack,
+        index,
+        mode,
+        game,
+        controller: GameController::default(),
+        phase: Phase::Playing,
+        layout: FieldLayout::new(
+            (
+                WINDOW_WIDTH / 8.,
+                WINDOW_HEIGHT / 2. - BLOCK_LENGTH * (FIELD_VISIBLE_UNIT_HEIGHT as f32 / 2.),
+            )
+                .into(),
+            BLOCK_LENGTH,
+        ),
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: PuzzleState,
+    delta: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    if input_cache.has_pushed(&ControlCode::Retry) {
+        return Ok(Next::transit(Ticket::PlayPuzzle {
+            pack: state.pack,
+            index: state.index,
+        }));
+    }
+
+    match state.phase {
+        Phase::Playing => {
+            if input_cache.has_pushed(&ControlCode::MenuBack) {
+                return Ok(Next::transit(Ticket::SelectPuzzle));
+            }
+
+            match update_game(ctx, input_cache, asset, &mut state, delta)? {
+                ModeProgress::Playing => (),
+                ModeProgress::Cleared => {
+                    FilePuzzleRepository.mark_solved(
+                        ctx,
+                        LOCAL_PLAYER,
+                        &state.pack.key(state.index),
+                    )?;
+                    state.phase = Phase::Finished { solved: true };
+                }
+                ModeProgress::Failed => state.phase = Phase::Finished { solved: false },
+            }
+        }
+        Phase::Finished { solved } => {
+            if input_cache.has_pushed(&ControlCode::MenuEnter) {
+                asset.audio.play_se(ctx, Se::MenuClick)?;
+
+                // 解けたら次のパズルに進み, 解けなかったらやり直す
+                let index = if solved && state.index + 1 < state.pack.puzzles.len() {
+                    state.index + 1
+                } else {
+                    state.index
+                };
+
+                return Ok(Next::transit(Ticket::PlayPuzzle {
+                    pack: state.pack,
+                    index,
+                }));
+            }
+            if input_cache.has_pushed(&ControlCode::MenuBack) {
+                asset.audio.play_se(ctx, Se::MenuClick)?;
+                return Ok(Next::transit(Ticket::SelectPuzzle));
+            }
+        }
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+fn update_game(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: &mut PuzzleState,
+    delta: &Duration,
+) -> GameResult<ModeProgress> {
+    let mut operations = state.controller.operate(&mut state.game, input_cache);
+    let has_put = operations.iter().any(|op| matches!(op, Operation::Put(_)));
+    if !has_put {
+        if let DroppedOrNothing::Dropped(Some(removed_lines)) = state.game.elapse(*delta) {
+            operations.push(Operation::Put(removed_lines));
+        }
+    }
+
+    for operation in operations {
+        match operation {
+            Operation::Moved => asset.audio.play_se(ctx, Se::MinoMove)?,
+            Operation::Spun => asset.audio.play_se(ctx, Se::MinoSpin)?,
+            Operation::SoftDropped => asset.audio.play_se(ctx, Se::MinoSoftDrop)?,
+            Operation::Held => (),
+            Operation::Put(removed_lines) => {
+                asset.audio.play_se(ctx, Se::MinoHardDrop)?;
+
+                state.game.put_and_spawn();
+                if !removed_lines.is_empty() {
+                    asset.audio.play_se(ctx, Se::RemoveLine)?;
+                    state.game.remove_lines();
+                }
+            }
+        }
+    }
+
+    let events = state.game.take_events();
+
+    Ok(state.mode.update(&mut state.game, &events))
+}
+
+pub fn draw(ctx: &mut Context, state: &PuzzleState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    let layout = &state.layout;
+    let game = &state.game;
+    let puzzle = &state.pack.puzzles[state.index];
+
+    renderer::draw_field(ctx, asset, layout)?;
+    renderer::draw_label(
+        ctx,
+        asset,
+        layout,
+        &format!("{}: {}", puzzle.name, puzzle.goal.description()),
+    )?;
+    draw_hud(ctx, asset, state)?;
+
+    if game.rule.hold {
+        renderer::draw_hold_panel(ctx, asset, layout, game.hold_mino)?;
+    }
+    renderer::draw_next_panel(
+        ctx,
+        asset,
+        layout,
+        game.bag.peek(game.rule.next_amount).as_slice(),
+    )?;
+
+    let playing = matches!(state.phase, Phase::Playing);
+    renderer::draw_minos(ctx, asset, layout, &game.board, playing)?;
+    if playing {
+        renderer::draw_ghost(ctx, layout, &game.board)?;
+    }
+
+    if let Phase::Finished { solved } = state.phase {
+        let guide = if solved && state.index + 1 < state.pack.puzzles.len() {
+            "ENTER: NEXT / BACK: PUZZLES"
+        } else {
+            "ENTER: RETRY / BACK: PUZZLES"
+        };
+
+        renderer::draw_result(ctx, asset, state.mode.result_title(solved), &[guide])?;
+    }
+
+    Ok(())
+}
+
+fn draw_hud(ctx: &mut Context, asset: &Asset, state: &PuzzleState) -> GameResult {
+    let x = state.layout.next_origin().x + 4. * state.layout.block_length;
+    let y = state.layout.origin.y + state.layout.field_height() / 12.;
+
+    for (idx, (name, value)) in state.mode.hud(&state.game).iter().enumerate() {
+        let text = graphics::Text::new(
+            graphics::TextFragment::new(format!("{0: <7}: {1: >9}", name, value))
+                .font(asset.font.vt323)
+                .scale(PxScale::from(HUD_FONT_SIZE)),
+        );
+
+        graphics::draw(
+            ctx,
+            &text,
+            DrawParam::default().dest([x, y + (idx as f32) * HUD_LINE_HEIGHT]),
+        )?;
+    }
+
+    Ok(())
+}
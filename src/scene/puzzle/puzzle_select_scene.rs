@@ -0,0 +1,166 @@
+use std::{collections::BTreeSet, time::Duration};
+
+use ggez::{
+    graphics,
+    graphics::{DrawParam, PxScale, Text, TextFragment},
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::{audio::Se, Asset},
+    infra::repo::file_puzzle_repository::FilePuzzleRepository,
+    model::{
+        control_code::ControlCode,
+        input_cache::InputCache,
+        repo::puzzle_repository::{PuzzleRepository, LOCAL_PLAYER},
+    },
+    scene::{
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+    },
+    tetris::puzzle::PuzzlePack,
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const ITEM_FONT_SIZE: f32 = 32.;
+const ITEM_LINE_HEIGHT: f32 = 50.;
+/// 一度に並べるパズルの数. これより多いときはカーソルに合わせて流れる.
+const VISIBLE_ITEM_AMOUNT: usize = 9;
+
+pub struct PuzzleSelectState {
+    packs: Vec<PuzzlePack>,
+    /// 全てのパックのパズルを並べたときの, パックとパズルの位置.
+    items: Vec<(usize, usize)>,
+    solved: BTreeSet<String>,
+    cursor: usize,
+}
+
+impl Scene for PuzzleSelectState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
+pub fn init(ctx: &mut Context, _: &mut Asset) -> GameResult<PuzzleSelectState> {
+    let packs = FilePuzzleRepository.packs(ctx)?;
+    let items = packs
+        .iter()
+        .enumerate()
+        .flat_map(|(pack_idx, pack)| (0..pack.puzzles.len()).map(move |idx| (pack_idx, idx)))
+        .collect();
+
+    Ok(PuzzleSelectState {
+        packs,
+        items,
+        solved: FilePuzzleRepository.solved(ctx, LOCAL_PLAYER)?,
+        cursor: 0,
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: PuzzleSelectState,
+    _: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    if input_cache.has_pushed(&ControlCode::MenuUp) && state.cursor > 0 {
+        state.cursor -= 1;
+    }
+    if input_cache.has_pushed(&ControlCode::MenuDown) && state.cursor + 1 < state.items.len() {
+        state.cursor += 1;
+    }
+
+    if input_cache.has_pushed(&ControlCode::MenuEnter) && !state.items.is_empty() {
+        asset.audio.play_se(ctx, Se::MenuClick)?;
+        let (pack_idx, index) = state.items[state.cursor];
+        let pack = state.packs.swap_remove(pack_idx);
+
+        return Ok(Next::transit(Ticket::PlayPuzzle { pack, index }));
+    }
+    if input_cache.has_pushed(&ControlCode::MenuBack) {
+        return Ok(Next::transit(Ticket::ShowTitle));
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+pub fn draw(ctx: &mut Context, state: &PuzzleSelectState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    if state.items.is_empty() {
+        let text = Text::new(
+            TextFragment::new("NO PUZZLE PACKS")
+                .font(asset.font.vt323)
+                .scale(PxScale::from(ITEM_FONT_SIZE)),
+        );
+        let x = WINDOW_WIDTH / 2. - text.width(ctx) / 2.;
+        graphics::draw(
+            ctx,
+            &text,
+            DrawParam::default().dest([x, WINDOW_HEIGHT / 2.]),
+        )?;
+    }
+
+    let first = (state.cursor + 1).saturating_sub(VISIBLE_ITEM_AMOUNT);
+    let visible = state
+        .items
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(VISIBLE_ITEM_AMOUNT);
+
+    for (row, (idx, &(pack_idx, puzzle_idx))) in visible.enumerate() {
+        let pack = &state.packs[pack_idx];
+        // 解いたことのあるパズルに印を付ける
+        let mark = if state.solved.contains(&pack.key(puzzle_idx)) {
+            "*"
+        } else {
+            " "
+        };
+
+        let text = Text::new(
+            TextFragment::new(format!(
+                "{} {} / {}",
+                mark, pack.name, pack.puzzles[puzzle_idx].name
+            ))
+            .font(asset.font.vt323)
+            .scale(PxScale::from(ITEM_FONT_SIZE)),
+        );
+        let x = WINDOW_WIDTH / 4.;
+        let y = WINDOW_HEIGHT / 4. + (row as f32) * ITEM_LINE_HEIGHT;
+
+        graphics::draw(ctx, &text, DrawParam::default().dest([x, y]))?;
+
+        if idx == state.cursor {
+            let cursor_scale = 0.5;
+            let cursor_y = y + text.height(ctx) / 2.
+                - f32::from(asset.image.cursor.height()) * cursor_scale / 2.;
+
+            graphics::draw(
+                ctx,
+                &asset.image.cursor,
+                DrawParam::default()
+                    .dest([x - 30., cursor_y])
+                    .scale([cursor_scale, cursor_scale]),
+            )?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,283 @@
+use std::time::Duration;
+
+use ggez::{
+    graphics,
+    graphics::{DrawMode, DrawParam, PxScale, Rect, Text, TextFragment},
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::{audio::Se, Asset},
+    model::{
+        control_code::ControlCode,
+        input_cache::InputCache,
+        play_record::{PlayRecord, Ranking},
+    },
+    scene::{
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+    },
+    tetris::{
+        mode::{format_precise_time, ModeKind},
+        model::score::ScoringAction,
+    },
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const TITLE_FONT_SIZE: f32 = 72.;
+const LINE_FONT_SIZE: f32 = 32.;
+const LINE_HEIGHT: f32 = 40.;
+const GUIDE_FONT_SIZE: f32 = 24.;
+
+const CLEARS: [(&str, ScoringAction); 8] = [
+    ("SINGLE", ScoringAction::Single),
+    ("DOUBLE", ScoringAction::Double),
+    ("TRIPLE", ScoringAction::Triple),
+    ("TETRIS", ScoringAction::Tetris),
+    ("T-SPIN", ScoringAction::TSpinSingle),
+    ("TSD", ScoringAction::TSpinDouble),
+    ("TST", ScoringAction::TSpinTriple),
+    ("PC", ScoringAction::PerfectClear),
+];
+
+/// # 結果画面
+///
+/// 終わったプレイの上に重ね, 記録と統計を自己ベストと比べて見せる.
+pub struct ResultsState {
+    kind: ModeKind,
+    seed: u64,
+    record: PlayRecord,
+    /// このプレイより前の自己ベスト.
+    best: Option<PlayRecord>,
+    rank: Option<usize>,
+}
+
+impl Scene for ResultsState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
+
+pub fn init(
+    _: &mut Context,
+    _: &mut Asset,
+    kind: ModeKind,
+    seed: u64,
+    record: PlayRecord,
+    best: Option<PlayRecord>,
+    rank: Option<usize>,
+) -> GameResult<ResultsState> {
+    Ok(ResultsState {
+        kind,
+        seed,
+        record,
+        best,
+        rank,
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    state: ResultsState,
+    _: &Duration,
+) -> GameResult<Next> {
+    let retry = Ticket::Retry {
+        kind: state.kind,
+        seed: state.seed,
+    };
+
+    if input_cache.has_pushed(&ControlCode::Retry) {
+        return Ok(Next::transit(retry));
+    }
+    if input_cache.has_pushed(&ControlCode::MenuEnter) {
+        asset.audio.play_se(ctx, Se::MenuClick)?;
+        return Ok(Next::transit(retry));
+    }
+    if input_cache.has_pushed(&ControlCode::MenuBack) {
+        asset.audio.play_se(ctx, Se::MenuClick)?;
+        return Ok(Next::transit(Ticket::ShowTitle));
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+pub fn draw(ctx: &mut Context, state: &ResultsState, asset: &mut Asset) -> GameResult {
+    let rect = graphics::Mesh::new_rectangle(
+        ctx,
+        DrawMode::fill(),
+        Rect::new(0., 0., WINDOW_WIDTH, WINDOW_HEIGHT),
+        graphics::Color::new(0., 0., 0., 0.8),
+    )?;
+    graphics::draw(ctx, &rect, DrawParam::default())?;
+
+    let title = Text::new(
+        TextFragment::new(state.record.title.as_str())
+            .font(asset.font.vt323)
+            .scale(PxScale::from(TITLE_FONT_SIZE)),
+    );
+    graphics::draw(
+        ctx,
+        &title,
+        DrawParam::default().dest([
+            WINDOW_WIDTH / 2. - title.width(ctx) / 2.,
+            WINDOW_HEIGHT / 10.,
+        ]),
+    )?;
+
+    let top = WINDOW_HEIGHT / 10. + TITLE_FONT_SIZE * 1.5;
+    draw_lines(ctx, asset, &summary_lines(state), WINDOW_WIDTH / 8., top)?;
+
+    let clears: Vec<_> = CLEARS
+        .iter()
+        .map(|(name, action)| {
+            format!(
+                "{0: <7}: {1: >5}",
+                name,
+                state.record.stats.clear_count(action)
+            )
+        })
+        .collect();
+    draw_lines(ctx, asset, &clears, WINDOW_WIDTH * 9. / 16., top)?;
+
+    let guide = Text::new(
+        TextFragment::new("ENTER: RETRY / BACK: TITLE")
+            .font(asset.font.vt323)
+            .scale(PxScale::from(GUIDE_FONT_SIZE)),
+    );
+    graphics::draw(
+        ctx,
+        &guide,
+        DrawParam::default().dest([
+            WINDOW_WIDTH / 2. - guide.width(ctx) / 2.,
+            WINDOW_HEIGHT * 9. / 10.,
+        ]),
+    )?;
+
+    Ok(())
+}
+
+fn summary_lines(state: &ResultsState) -> Vec<String> {
+    let record = &state.record;
+    let stats = &record.stats;
+    let ranking = state.kind.ranking();
+
+    let best = match &state.best {
+        Some(best) if ranking.is_ranked(record) => format!(
+            "{} ({})",
+            ranked_value(ranking, best),
+            difference(ranking, record, best)
+        ),
+        Some(best) => ranked_value(ranking, best),
+        None => "-".to_owned(),
+    };
+    let rank = match state.rank {
+        Some(1) => "#1 NEW BEST!".to_owned(),
+        Some(rank) => format!("#{}", rank),
+        None => "-".to_owned(),
+    };
+
+    vec![
+        format!("{0: <8}: {1}", "TIME", format_precise_time(&record.elapsed)),
+        format!("{0: <8}: {1}", "SCORE", record.score),
+        format!("{0: <8}: {1}", "LINES", record.lines),
+        format!("{0: <8}: {1}", "BEST", best),
+        format!("{0: <8}: {1}", "RANK", rank),
+        format!("{0: <8}: {1}", "PIECES", stats.pieces),
+        format!("{0: <8}: {1:.2}", "PPS", stats.pps(&record.elapsed)),
+        format!("{0: <8}: {1:.2}", "KPP", stats.kpp()),
+        format!("{0: <8}: {1}", "FINESSE", stats.finesse_faults),
+    ]
+}
+
+fn draw_lines(ctx: &mut Context, asset: &Asset, lines: &[String], x: f32, y: f32) -> GameResult {
+    for (idx, line) in lines.iter().enumerate() {
+        let text = Text::new(
+            TextFragment::new(line.as_str())
+                .font(asset.font.vt323)
+                .scale(PxScale::from(LINE_FONT_SIZE)),
+        );
+
+        graphics::draw(
+            ctx,
+            &text,
+            DrawParam::default().dest([x, y + (idx as f32) * LINE_HEIGHT]),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// 順位付けに使う値.
+fn ranked_value(ranking: Ranking, record: &PlayRecord) -> String {
+    match ranking {
+        Ranking::HigherScore => record.score.to_string(),
+        Ranking::ShorterTime | Ranking::LongerTime => format_precise_time(&record.elapsed),
+    }
+}
+
+/// 自己ベストとの差. 時間は短くなると `-` に, 得点は高くなると `+` になる.
+fn difference(ranking: Ranking, record: &PlayRecord, best: &PlayRecord) -> String {
+    match ranking {
+        Ranking::HigherScore => format!("{:+}", record.score as i64 - best.score as i64),
+        Ranking::ShorterTime | Ranking::LongerTime => {
+            let (sign, diff) = if record.elapsed < best.elapsed {
+                ('-', best.elapsed - record.elapsed)
+            } else {
+                ('+', record.elapsed - best.elapsed)
+            };
+
+            format!("{}{}.{:>03}", sign, diff.as_secs(), diff.subsec_millis())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::tetris::stats::PlayStats;
+
+    fn record(score: usize, millis: u64) -> PlayRecord {
+        PlayRecord::new(
+            "test",
+            score,
+            40,
+            Duration::from_millis(millis),
+            PlayStats::new(),
+            true,
+            "CLEAR",
+        )
+    }
+
+    #[test_case(Ranking::ShorterTime, 45_120, 46_000, "-0.880")]
+    #[test_case(Ranking::ShorterTime, 61_500, 60_000, "+1.500")]
+    #[test_case(Ranking::HigherScore, 1_200, 1_500, "-300")]
+    fn test_difference(ranking: Ranking, current: u64, best: u64, expected: &str) {
+        let (current, best) = match ranking {
+            Ranking::HigherScore => (record(current as usize, 0), record(best as usize, 0)),
+            _ => (record(0, current), record(0, best)),
+        };
+
+        assert_eq!(difference(ranking, &current, &best), expected);
+    }
+}
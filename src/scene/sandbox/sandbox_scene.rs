@@ -0,0 +1,375 @@
+use std::time::Duration;
+
+use ggez::{
+    event::MouseButton,
+    graphics,
+    graphics::{DrawParam, PxScale},
+    input::mouse,
+    Context,
+    GameResult,
+};
+
+use crate::{
+    asset::{audio::Se, Asset},
+    infra::repo::file_sandbox_repository::FileSandboxRepository,
+    model::{
+        control_code::ControlCode,
+        input_cache::InputCache,
+        repo::sandbox_repository::SandboxRepository,
+        sandbox_setup::SandboxSetup,
+    },
+    scene::{
+        playfield::{
+            controller::{GameController, Operation},
+            renderer,
+            renderer::FieldLayout,
+        },
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+    },
+    tetris::{
+        board::{Field, FIELD_UNIT_HEIGHT, FIELD_UNIT_WIDTH, FIELD_VISIBLE_UNIT_HEIGHT},
+        game::{DroppedOrNothing, Game, GameEvent},
+        model::{mino_entity::MinoEntity, tetrimino::Tetrimino},
+    },
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+};
+
+const BLOCK_LENGTH: f32 = 32.;
+const GUIDE_FONT_SIZE: f32 = 24.;
+const GUIDE_LINE_HEIGHT: f32 = 1.2 * GUIDE_FONT_SIZE;
+/// ネクストの欄に並べる数. 並びがこれより長くても, 作ったものは全て使われる.
+const SHOWN_QUEUE_AMOUNT: usize = 5;
+const BACKSPACE: char = '\u{8}';
+
+const GUIDES: [&str; 9] = [
+    "ARROWS: MOVE",
+    "ENTER/CLICK: PAINT",
+    "RIGHT CLICK: ERASE",
+    "0-8: BRUSH",
+    "T S Z L J O I (SHIFT): ADD NEXT",
+    "BACKSPACE: REMOVE NEXT",
+    "H: HOLD  C: CLEAR",
+    "V: SAVE  L: LOAD",
+    "P: PLAY  ESC: BACK",
+];
+
+/// # サンドボックス
+///
+/// 盤面のマスを好きな色で塗り, ホールドとネクストを決めて, その場面から遊べる.
+/// 遊んでいる途中で戻ると, 遊び始める前の盤面の編集に戻る.
+pub struct SandboxState {
+    field: Field,
+    hold: Option<Tetrimino>,
+    queue: Vec<Tetrimino>,
+    cursor: (usize, usize),
+    brush: MinoEntity,
+    message: Option<&'static str>,
+    phase: Phase,
+    layout: FieldLayout,
+}
+
+enum Phase {
+    Editing,
+    Playing {
+        game: Box<Game>,
+        controller: GameController,
+    },
+}
+
+impl Scene for SandboxState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        typed: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, asset, typed, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
+pub fn init(_: &mut Context, _: &mut Asset) -> GameResult<SandboxState> {
+    Ok(SandboxState {
+        field: [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT],
+        hold: None,
+        queue: Vec::new(),
+        cursor: (0, FIELD_UNIT_HEIGHT - 1),
+        brush: MinoEntity::GARBAGE,
+        message: None,
+        phase: Phase::Editing,
+        layout: FieldLayout::new(
+            (
+                WINDOW_WIDTH / 8.,
+                WINDOW_HEIGHT / 2. - BLOCK_LENGTH * (FIELD_VISIBLE_UNIT_HEIGHT as f32 / 2.),
+            )
+                .into(),
+            BLOCK_LENGTH,
+        ),
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    typed: &str,
+    state: SandboxState,
+    delta: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    match state.phase {
+        Phase::Editing => {
+            if input_cache.has_pushed(&ControlCode::MenuBack) {
+                return Ok(Next::transit(Ticket::ShowTitle));
+            }
+
+            edit(ctx, input_cache, asset, typed, &mut state)?;
+        }
+        Phase::Playing {
+            ref mut game,
+            ref mut controller,
+        } => {
+            let back = input_cache.has_pushed(&ControlCode::MenuBack);
+            let topped_out = play(ctx, input_cache, asset, game, controller, delta)?;
+
+            if back || topped_out {
+                state.message = topped_out.then_some("TOPPED OUT");
+                state.phase = Phase::Editing;
+            }
+        }
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+fn edit(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    typed: &str,
+    state: &mut SandboxState,
+) -> GameResult {
+    let (x, y) = state.cursor;
+    if input_cache.has_pushed(&ControlCode::MenuLeft) && x > 0 {
+        state.cursor.0 -= 1;
+    }
+    if input_cache.has_pushed(&ControlCode::MenuRight) && x + 1 < FIELD_UNIT_WIDTH {
+        state.cursor.0 += 1;
+    }
+    if input_cache.has_pushed(&ControlCode::MenuUp)
+        && y > FIELD_UNIT_HEIGHT - FIELD_VISIBLE_UNIT_HEIGHT
+    {
+        state.cursor.1 -= 1;
+    }
+    if input_cache.has_pushed(&ControlCode::MenuDown) && y + 1 < FIELD_UNIT_HEIGHT {
+        state.cursor.1 += 1;
+    }
+    if input_cache.has_pushed(&ControlCode::MenuEnter) {
+        let (x, y) = state.cursor;
+        state.field[y][x] = state.brush;
+    }
+
+    // マウスで指したマスにカーソルを合わせ, 押している間は塗り続ける
+    let pointer = mouse::position(ctx);
+    if let Some(cell) = state.layout.cell_at(pointer.x, pointer.y) {
+        let (x, y) = cell;
+        if mouse::button_pressed(ctx, MouseButton::Left) {
+            state.cursor = cell;
+            state.field[y][x] = state.brush;
+        } else if mouse::button_pressed(ctx, MouseButton::Right) {
+            state.cursor = cell;
+            state.field[y][x] = MinoEntity::AIR;
+        }
+    }
+
+    for c in typed.chars() {
+        match c {
+            '0'..='8' => state.brush = brush(c),
+            'T' | 'S' | 'Z' | 'L' | 'J' | 'O' | 'I' => state.queue.push(mino(c)),
+            BACKSPACE => {
+                state.queue.pop();
+            }
+            'h' => state.hold = next_hold(state.hold),
+            'c' => state.field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT],
+            'v' => {
+                let setup = SandboxSetup::capture(&state.field, state.hold, &state.queue);
+                FileSandboxRepository.save(ctx, &setup)?;
+                state.message = Some("SAVED");
+            }
+            'l' => match FileSandboxRepository.load(ctx)? {
+                Some(setup) => {
+                    state.field = setup.field();
+                    state.hold = setup.hold;
+                    state.queue = setup.queue;
+                    state.message = Some("LOADED");
+                }
+                None => state.message = Some("NOTHING SAVED"),
+            },
+            'p' => {
+                asset.audio.play_se(ctx, Se::GameStart)?;
+
+                let setup = SandboxSetup::capture(&state.field, state.hold, &state.queue);
+                state.message = None;
+                state.phase = Phase::Playing {
+                    game: Box::new(setup.create_game()),
+                    controller: GameController::default(),
+                };
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+/// `0` は空気, `1` から `7` は各ミノの色, `8` はおじゃまブロックの筆.
+fn brush(c: char) -> MinoEntity {
+    match c.to_digit(10) {
+        Some(0) => MinoEntity::AIR,
+        Some(n @ 1..=7) => Tetrimino::all()[n as usize - 1].block().into(),
+        _ => MinoEntity::GARBAGE,
+    }
+}
+
+fn mino(c: char) -> Tetrimino {
+    match c {
+        'T' => Tetrimino::T,
+        'S' => Tetrimino::S,
+        'Z' => Tetrimino::Z,
+        'L' => Tetrimino::L,
+        'J' => Tetrimino::J,
+        'O' => Tetrimino::O,
+        _ => Tetrimino::I,
+    }
+}
+
+/// 空, T, S, Z, L, J, O, I の順に切り替える.
+fn next_hold(hold: Option<Tetrimino>) -> Option<Tetrimino> {
+    let all = Tetrimino::all();
+    match hold {
+        None => all.first().copied(),
+        Some(held) => all
+            .iter()
+            .position(|&mino| mino == held)
+            .and_then(|idx| all.get(idx + 1))
+            .copied(),
+    }
+}
+
+/// 積み上がったときに true を返す.
+fn play(
+    ctx: &mut Context,
+    input_cache: &mut InputCache,
+    asset: &mut Asset,
+    game: &mut Game,
+    controller: &mut GameController,
+    delta: &Duration,
+) -> GameResult<bool> {
+    let mut operations = controller.operate(game, input_cache);
+    let has_put = operations.iter().any(|op| matches!(op, Operation::Put(_)));
+    if !has_put {
+        if let DroppedOrNothing::Dropped(Some(removed_lines)) = game.elapse(*delta) {
+            operations.push(Operation::Put(removed_lines));
+        }
+    }
+
+    for operation in operations {
+        match operation {
+            Operation::Moved => asset.audio.play_se(ctx, Se::MinoMove)?,
+            Operation::Spun => asset.audio.play_se(ctx, Se::MinoSpin)?,
+            Operation::SoftDropped => asset.audio.play_se(ctx, Se::MinoSoftDrop)?,
+            Operation::Held => (),
+            Operation::Put(removed_lines) => {
+                asset.audio.play_se(ctx, Se::MinoHardDrop)?;
+
+                game.put_and_spawn();
+                if !removed_lines.is_empty() {
+                    asset.audio.play_se(ctx, Se::RemoveLine)?;
+                    game.remove_lines();
+                }
+            }
+        }
+    }
+
+    Ok(game
+        .take_events()
+        .iter()
+        .any(|event| matches!(event, GameEvent::ToppedOut)))
+}
+
+pub fn draw(ctx: &mut Context, state: &SandboxState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    let layout = &state.layout;
+    renderer::draw_field(ctx, asset, layout)?;
+
+    match state.phase {
+        Phase::Editing => {
+            renderer::draw_label(ctx, asset, layout, state.message.unwrap_or("SANDBOX"))?;
+            renderer::draw_hold_panel(ctx, asset, layout, state.hold)?;
+            renderer::draw_next_panel(
+                ctx,
+                asset,
+                layout,
+                &state.queue[..state.queue.len().min(SHOWN_QUEUE_AMOUNT)],
+            )?;
+            renderer::draw_blocks(ctx, asset, layout, &state.field, &[])?;
+
+            let (x, y) = state.cursor;
+            renderer::draw_cell_cursor(ctx, layout, x, y)?;
+
+            draw_guides(ctx, asset, state)?;
+        }
+        Phase::Playing { ref game, .. } => {
+            renderer::draw_label(ctx, asset, layout, "ESC: EDIT")?;
+            renderer::draw_hold_panel(ctx, asset, layout, game.hold_mino)?;
+            renderer::draw_next_panel(
+                ctx,
+                asset,
+                layout,
+                game.bag.peek(game.rule.next_amount).as_slice(),
+            )?;
+            renderer::draw_minos(ctx, asset, layout, &game.board, true)?;
+            renderer::draw_ghost(ctx, layout, &game.board)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn draw_guides(ctx: &mut Context, asset: &Asset, state: &SandboxState) -> GameResult {
+    let x = state.layout.next_origin().x + 4. * state.layout.block_length;
+    let y = state.layout.origin.y;
+
+    let brush = match state.brush.block() {
+        Some(_) if state.brush == MinoEntity::GARBAGE => "GARBAGE".to_owned(),
+        Some(block) => format!("{:?}", block),
+        None => "AIR".to_owned(),
+    };
+    let lines = std::iter::once(format!("BRUSH: {}", brush))
+        .chain(GUIDES.iter().map(|guide| guide.to_string()));
+
+    for (idx, line) in lines.enumerate() {
+        let text = graphics::Text::new(
+            graphics::TextFragment::new(line)
+                .font(asset.font.vt323)
+                .scale(PxScale::from(GUIDE_FONT_SIZE)),
+        );
+
+        graphics::draw(
+            ctx,
+            &text,
+            DrawParam::default().dest([x, y + (idx as f32) * GUIDE_LINE_HEIGHT]),
+        )?;
+    }
+
+    Ok(())
+}
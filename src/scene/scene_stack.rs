@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use ggez::{graphics, Context, GameResult};
+
+use crate::{
+    asset::Asset,
+    model::input_cache::InputCache,
+    scene::ticket::{Next, Ticket},
+};
+
+/// # シーン
+///
+/// シーンごとの状態を持ち, 1フレームごとに更新と描画をする.
+/// 上に別のシーンが積まれている間は更新されず, 積まれたときと戻ったときに通知を受ける.
+pub trait Scene {
+    /// 状態を受け取って更新し, 次にどのシーンを動かすかを返す.
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        typed: &str,
+        delta: &Duration,
+    ) -> GameResult<Next>;
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult;
+
+    /// 上に別のシーンが積まれたときに呼ばれる.
+    fn on_pause(&mut self, _: &mut Context, _: &mut Asset) -> GameResult {
+        Ok(())
+    }
+
+    /// 上に積まれたシーンが取り除かれ, 再び動き出すときに呼ばれる.
+    fn on_resume(&mut self, _: &mut Context, _: &mut Asset) -> GameResult {
+        Ok(())
+    }
+
+    /// 下のシーンに重ねて描くかどうか. 重ねるシーンは画面を消さずに描く.
+    fn is_overlay(&self) -> bool {
+        false
+    }
+}
+
+/// # シーンのスタック
+///
+/// 一番上のシーンだけを更新し, 重ねるシーンの下にあるシーンも合わせて描く.
+/// ポーズメニューなどを積んでも, 下のシーンの状態はそのまま残る.
+pub struct SceneStack {
+    scenes: Vec<Box<dyn Scene>>,
+}
+
+impl SceneStack {
+    pub fn new(scene: Box<dyn Scene>) -> SceneStack {
+        SceneStack {
+            scenes: vec![scene],
+        }
+    }
+
+    /// 全てのシーンが取り除かれ, ゲームを終えるかどうか.
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    pub fn update(
+        &mut self,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        asset: &mut Asset,
+        typed: &str,
+        delta: &Duration,
+    ) -> GameResult {
+        let scene = match self.scenes.pop() {
+            Some(scene) => scene,
+            None => return Ok(()),
+        };
+
+        match scene.update(ctx, input_cache, asset, typed, delta)? {
+            Next::Continue { scene } => {
+                self.scenes.push(scene);
+            }
+            Next::Push { scene, ticket } => {
+                let mut scene = scene;
+                scene.on_pause(ctx, asset)?;
+                self.scenes.push(scene);
+
+                self.push(ctx, asset, ticket)?;
+            }
+            Next::Pop => {
+                if let Some(scene) = self.scenes.last_mut() {
+                    scene.on_resume(ctx, asset)?;
+                }
+            }
+            Next::Transit { ticket } => {
+                self.scenes.clear();
+
+                self.push(ctx, asset, ticket)?;
+            }
+            Next::Exit => {
+                self.scenes.clear();
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        let bottom = self
+            .scenes
+            .iter()
+            .rposition(|scene| !scene.is_overlay())
+            .unwrap_or(0);
+
+        for scene in &self.scenes[bottom..] {
+            scene.draw(ctx, asset)?;
+        }
+
+        graphics::present(ctx)
+    }
+
+    fn push(&mut self, ctx: &mut Context, asset: &mut Asset, ticket: Ticket) -> GameResult {
+        let scene = ticket.go(ctx, asset)?;
+        self.scenes.push(scene);
+
+        Ok(())
+    }
+}
@@ -1,34 +1,134 @@
 use ggez::{Context, GameResult};
 
 use crate::{
+    infra::net::tcp_peer::TcpPeer,
+    model::play_record::PlayRecord,
     scene::{
-        scene_state::{SceneState, SceneState::ForTitle},
+        daily::daily_scene,
+        mode_select::mode_select_scene,
+        online::{lobby_scene, online_versus_scene},
+        pause::pause_scene,
+        play::play_scene,
+        puzzle::{puzzle_scene, puzzle_select_scene},
+        results::results_scene,
+        sandbox::sandbox_scene,
+        scene_stack::Scene,
         title::title_scene,
+        versus::versus_scene,
     },
+    tetris::{mode::ModeKind, puzzle::PuzzlePack},
     Asset,
 };
 
 pub enum Ticket {
     ShowTitle,
+    PlayVersus,
+    ShowLobby,
+    PlayOnline {
+        peer: TcpPeer,
+        seed: u64,
+    },
+    SelectMode,
+    Play {
+        kind: ModeKind,
+    },
+    SelectPuzzle,
+    PlayPuzzle {
+        pack: PuzzlePack,
+        index: usize,
+    },
+    ShowSandbox,
+    ShowDaily,
+    /// `kind` のプレイに重ねるポーズメニュー.
+    Pause {
+        kind: ModeKind,
+        seed: u64,
+    },
+    /// 直前のプレイのシードが `seed` の `kind` を, 設定に従ってすぐに始め直す.
+    Retry {
+        kind: ModeKind,
+        seed: u64,
+    },
+    /// 終わった `kind` のプレイに重ねる結果画面. `best` はこのプレイより前の自己ベスト.
+    ShowResults {
+        kind: ModeKind,
+        seed: u64,
+        record: Box<PlayRecord>,
+        best: Option<Box<PlayRecord>>,
+        rank: Option<usize>,
+    },
 }
 
 impl Ticket {
-    pub fn go(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult<SceneState> {
-        match &self {
-            Ticket::ShowTitle => title_scene::init(ctx, asset).map(|state| ForTitle { state }),
+    pub fn go(self, ctx: &mut Context, asset: &mut Asset) -> GameResult<Box<dyn Scene>> {
+        match self {
+            Ticket::ShowTitle => title_scene::init(ctx, asset).map(boxed),
+            Ticket::PlayVersus => versus_scene::init(ctx, asset).map(boxed),
+            Ticket::ShowLobby => lobby_scene::init(ctx, asset).map(boxed),
+            Ticket::PlayOnline { peer, seed } => {
+                online_versus_scene::init(ctx, asset, peer, seed).map(boxed)
+            }
+            Ticket::SelectMode => mode_select_scene::init(ctx, asset).map(boxed),
+            Ticket::Play { kind } => play_scene::init(ctx, asset, kind).map(boxed),
+            Ticket::SelectPuzzle => puzzle_select_scene::init(ctx, asset).map(boxed),
+            Ticket::PlayPuzzle { pack, index } => {
+                puzzle_scene::init(ctx, asset, pack, index).map(boxed)
+            }
+            Ticket::ShowSandbox => sandbox_scene::init(ctx, asset).map(boxed),
+            Ticket::ShowDaily => daily_scene::init(ctx, asset).map(boxed),
+            Ticket::Pause { kind, seed } => pause_scene::init(ctx, asset, kind, seed).map(boxed),
+            Ticket::Retry { kind, seed } => play_scene::retry(ctx, asset, kind, seed).map(boxed),
+            Ticket::ShowResults {
+                kind,
+                seed,
+                record,
+                best,
+                rank,
+            } => results_scene::init(ctx, asset, kind, seed, *record, best.map(|b| *b), rank)
+                .map(boxed),
         }
     }
 }
 
+fn boxed<S: Scene + 'static>(scene: S) -> Box<dyn Scene> {
+    Box::new(scene)
+}
+
 pub enum Next {
-    Continue { state: SceneState },
-    Transit { ticket: Ticket },
+    /// 同じシーンを動かし続ける.
+    Continue {
+        scene: Box<dyn Scene>,
+    },
+    /// `scene` を止めたまま残し, その上に新しいシーンを積む.
+    Push {
+        scene: Box<dyn Scene>,
+        ticket: Ticket,
+    },
+    /// 今のシーンを取り除き, 下のシーンに戻る.
+    Pop,
+    /// 積まれているシーンを全て取り除き, 新しいシーンに移る.
+    Transit {
+        ticket: Ticket,
+    },
     Exit,
 }
 
 impl Next {
-    pub fn do_continue(state: SceneState) -> Next {
-        Next::Continue { state }
+    pub fn do_continue<S: Scene + 'static>(scene: S) -> Next {
+        Next::Continue {
+            scene: Box::new(scene),
+        }
+    }
+
+    pub fn push<S: Scene + 'static>(scene: S, ticket: Ticket) -> Next {
+        Next::Push {
+            scene: Box::new(scene),
+            ticket,
+        }
+    }
+
+    pub fn pop() -> Next {
+        Next::Pop
     }
 
     pub fn transit(ticket: Ticket) -> Next {
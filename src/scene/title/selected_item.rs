@@ -4,6 +4,12 @@ use num_traits::{FromPrimitive, ToPrimitive};
 #[derive(FromPrimitive, ToPrimitive, IntoEnumIterator, Debug, PartialEq, Eq, Hash)]
 pub enum SelectedItem {
     PlayFortyLine,
+    PlayDaily,
+    SelectMode,
+    SelectPuzzle,
+    ShowSandbox,
+    PlayVersus,
+    PlayOnline,
     Exit,
 }
 
@@ -15,6 +21,12 @@ impl SelectedItem {
     pub fn name(&self) -> &'static str {
         match *self {
             SelectedItem::PlayFortyLine => "Play 40Line",
+            SelectedItem::PlayDaily => "Daily Challenge",
+            SelectedItem::SelectMode => "Modes",
+            SelectedItem::SelectPuzzle => "Puzzles",
+            SelectedItem::ShowSandbox => "Sandbox",
+            SelectedItem::PlayVersus => "Versus",
+            SelectedItem::PlayOnline => "Online Versus",
             SelectedItem::Exit => "Exit",
         }
     }
@@ -38,13 +50,25 @@ mod tests {
 
     use super::*;
 
-    #[test_case(SelectedItem::PlayFortyLine, Some(SelectedItem::Exit))]
+    #[test_case(SelectedItem::PlayFortyLine, Some(SelectedItem::PlayDaily))]
+    #[test_case(SelectedItem::PlayDaily, Some(SelectedItem::SelectMode))]
+    #[test_case(SelectedItem::SelectMode, Some(SelectedItem::SelectPuzzle))]
+    #[test_case(SelectedItem::SelectPuzzle, Some(SelectedItem::ShowSandbox))]
+    #[test_case(SelectedItem::ShowSandbox, Some(SelectedItem::PlayVersus))]
+    #[test_case(SelectedItem::PlayVersus, Some(SelectedItem::PlayOnline))]
+    #[test_case(SelectedItem::PlayOnline, Some(SelectedItem::Exit))]
     #[test_case(SelectedItem::Exit, None)]
     fn test_next(src: SelectedItem, ans: Option<SelectedItem>) {
         assert_eq!(src.next(), ans)
     }
 
-    #[test_case(SelectedItem::Exit, Some(SelectedItem::PlayFortyLine))]
+    #[test_case(SelectedItem::Exit, Some(SelectedItem::PlayOnline))]
+    #[test_case(SelectedItem::PlayOnline, Some(SelectedItem::PlayVersus))]
+    #[test_case(SelectedItem::PlayVersus, Some(SelectedItem::ShowSandbox))]
+    #[test_case(SelectedItem::ShowSandbox, Some(SelectedItem::SelectPuzzle))]
+    #[test_case(SelectedItem::SelectPuzzle, Some(SelectedItem::SelectMode))]
+    #[test_case(SelectedItem::SelectMode, Some(SelectedItem::PlayDaily))]
+    #[test_case(SelectedItem::PlayDaily, Some(SelectedItem::PlayFortyLine))]
     #[test_case(SelectedItem::PlayFortyLine, None)]
     fn test_prev(src: SelectedItem, ans: Option<SelectedItem>) {
         assert_eq!(src.prev(), ans)
@@ -14,13 +14,16 @@ use crate::{
     model::xytuple::F32XYTuple,
     scene::{
         animation_property::{AnimationProperties, AnimationProperty},
+        scene_stack::Scene,
         timer::Timer,
         title::selected_item::SelectedItem,
     },
+    tetris::mode::ModeKind,
     Asset,
     ControlCode,
     InputCache,
     Next,
+    Ticket,
     WINDOW_HEIGHT,
     WINDOW_WIDTH,
 };
@@ -152,6 +155,23 @@ impl TitleDrawState {
     }
 }
 
+impl Scene for TitleState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        input_cache: &mut InputCache,
+        _: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, input_cache, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
 pub fn init(ctx: &mut Context, asset: &mut Asset) -> GameResult<TitleState> {
     asset.audio.play_bgm(ctx, Bgm::Title)?;
 
@@ -191,12 +211,22 @@ pub fn update(
     }
     if input_cache.has_pushed(&ControlCode::MenuEnter) {
         match state.cursor {
-            SelectedItem::PlayFortyLine => unimplemented!(),
+            SelectedItem::PlayFortyLine => {
+                return Ok(Next::transit(Ticket::Play {
+                    kind: ModeKind::FortyLine,
+                }))
+            }
+            SelectedItem::PlayDaily => return Ok(Next::transit(Ticket::ShowDaily)),
+            SelectedItem::SelectMode => return Ok(Next::transit(Ticket::SelectMode)),
+            SelectedItem::SelectPuzzle => return Ok(Next::transit(Ticket::SelectPuzzle)),
+            SelectedItem::ShowSandbox => return Ok(Next::transit(Ticket::ShowSandbox)),
+            SelectedItem::PlayVersus => return Ok(Next::transit(Ticket::PlayVersus)),
+            SelectedItem::PlayOnline => return Ok(Next::transit(Ticket::ShowLobby)),
             SelectedItem::Exit => return Ok(Next::exit()),
         }
     }
 
-    Ok(Next::do_continue(state.into()))
+    Ok(Next::do_continue(state))
 }
 
 pub fn draw(ctx: &mut Context, state: &TitleState, asset: &mut Asset) -> GameResult {
@@ -251,7 +281,5 @@ pub fn draw(ctx: &mut Context, state: &TitleState, asset: &mut Asset) -> GameRes
         }
     }
 
-    graphics::present(ctx)?;
-
     Ok(())
 }
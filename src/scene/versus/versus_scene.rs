@@ -0,0 +1,369 @@
+use std::time::Duration;
+
+use ggez::{graphics, Context, GameResult};
+use rand::Rng;
+
+use crate::{
+    asset::{
+        audio::{Bgm, Se},
+        Asset,
+    },
+    infra::repo::versus_control_code_repository::VersusControlCodeRepository,
+    input::find_control_codes,
+    model::{control_code::ControlCode, input_cache::InputCache},
+    scene::{
+        playfield::{
+            controller::{GameController, Operation},
+            renderer,
+            renderer::FieldLayout,
+        },
+        scene_stack::Scene,
+        ticket::{Next, Ticket},
+        timer::Timer,
+    },
+    tetris::{
+        attack::{AttackTable, IncomingGarbage},
+        board::FIELD_UNIT_WIDTH,
+        game::{DroppedOrNothing, Game, GameEvent},
+    },
+};
+
+/// 先にこの回数だけラウンドを取ったプレイヤーが勝つ.
+const WINS_TO_MATCH: usize = 3;
+const COUNTDOWN_SEC: u64 = 3;
+const ROUND_INTERVAL: Duration = Duration::from_secs(2);
+
+const BLOCK_LENGTH: f32 = 24.;
+
+const VISIBLE_NEXT_MINO_AMOUNT: usize = 5;
+
+pub struct VersusState {
+    players: [Player; 2],
+    controller: GameController,
+    attack_table: AttackTable,
+    phase: Phase,
+}
+
+struct Player {
+    game: Game,
+    input_cache: InputCache,
+    control_code_repo: VersusControlCodeRepository,
+    garbage: IncomingGarbage,
+    topped_out: bool,
+    wins: usize,
+    layout: FieldLayout,
+}
+
+impl Player {
+    fn new(control_code_repo: VersusControlCodeRepository, screen_index: usize) -> Player {
+        Player {
+            game: Game::new(),
+            input_cache: InputCache::new(),
+            control_code_repo,
+            garbage: IncomingGarbage::new(),
+            topped_out: false,
+            wins: 0,
+            layout: FieldLayout::split_screen(screen_index, BLOCK_LENGTH),
+        }
+    }
+
+    /// 次のラウンドのために盤面を作り直す. 勝利数は引き継ぐ.
+    fn reset_round(&mut self) {
+        self.game = Game::new();
+        self.garbage = IncomingGarbage::new();
+        self.topped_out = false;
+    }
+
+    fn receive_inputs(&mut self, ctx: &Context, delta: &Duration) {
+        let inputs = find_control_codes(ctx, &self.control_code_repo);
+        self.input_cache.receive_inputs(&inputs, delta);
+    }
+}
+
+enum Phase {
+    Countdown { timer: Timer, remaining: u64 },
+    Playing,
+    RoundOver { winner: Option<usize>, timer: Timer },
+    MatchOver { winner: usize },
+}
+
+impl Phase {
+    fn countdown() -> Phase {
+        Phase::Countdown {
+            timer: Timer::repeat(Duration::from_secs(1), COUNTDOWN_SEC as u32),
+            remaining: COUNTDOWN_SEC,
+        }
+    }
+}
+
+impl Scene for VersusState {
+    fn update(
+        self: Box<Self>,
+        ctx: &mut Context,
+        _: &mut InputCache,
+        asset: &mut Asset,
+        _: &str,
+        delta: &Duration,
+    ) -> GameResult<Next> {
+        update(ctx, asset, *self, delta)
+    }
+
+    fn draw(&self, ctx: &mut Context, asset: &mut Asset) -> GameResult {
+        draw(ctx, self, asset)
+    }
+}
+
+pub fn init(ctx: &mut Context, asset: &mut Asset) -> GameResult<VersusState> {
+    asset.audio.stop_bgm();
+    asset.audio.play_se(ctx, Se::CountdownTick)?;
+
+    Ok(VersusState {
+        players: [
+            Player::new(VersusControlCodeRepository::PlayerOne, 0),
+            Player::new(VersusControlCodeRepository::PlayerTwo, 1),
+        ],
+        controller: GameController::default(),
+        attack_table: AttackTable::guideline(),
+        phase: Phase::countdown(),
+    })
+}
+
+pub fn update(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    state: VersusState,
+    delta: &Duration,
+) -> GameResult<Next> {
+    let mut state = state;
+
+    for player in &mut state.players {
+        player.receive_inputs(ctx, delta);
+    }
+
+    match state.phase {
+        Phase::Countdown {
+            ref mut timer,
+            ref mut remaining,
+        } => {
+            timer.elapse(*delta);
+
+            if timer.consume_if_beep() {
+                *remaining -= 1;
+
+                if *remaining == 0 {
+                    asset.audio.play_bgm(ctx, Bgm::InGame)?;
+                    asset.audio.play_se(ctx, Se::GameStart)?;
+                    state.phase = Phase::Playing;
+                } else {
+                    asset.audio.play_se(ctx, Se::CountdownTick)?;
+                }
+            }
+        }
+        Phase::Playing => {
+            for idx in 0..state.players.len() {
+                update_player(ctx, asset, &mut state, idx, delta)?;
+            }
+
+            let losers = state
+                .players
+                .iter()
+                .map(|player| player.topped_out)
+                .collect::<Vec<_>>();
+            if losers.contains(&true) {
+                state.phase = finish_round(ctx, asset, &mut state.players, &losers)?;
+            }
+        }
+        Phase::RoundOver { ref mut timer, .. } => {
+            timer.elapse(*delta);
+
+            if timer.consume_if_beep() {
+                for player in &mut state.players {
+                    player.reset_round();
+                }
+
+                asset.audio.play_se(ctx, Se::CountdownTick)?;
+                state.phase = Phase::countdown();
+            }
+        }
+        Phase::MatchOver { .. } => {
+            let pushed = |code: &ControlCode| {
+                state
+                    .players
+                    .iter()
+                    .any(|player| player.input_cache.has_pushed(code))
+            };
+
+            if pushed(&ControlCode::MenuEnter) {
+                asset.audio.play_se(ctx, Se::MenuClick)?;
+                return Ok(Next::transit(Ticket::PlayVersus));
+            }
+            if pushed(&ControlCode::MenuBack) {
+                asset.audio.play_se(ctx, Se::MenuClick)?;
+                return Ok(Next::transit(Ticket::ShowTitle));
+            }
+        }
+    }
+
+    Ok(Next::do_continue(state))
+}
+
+fn update_player(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    state: &mut VersusState,
+    idx: usize,
+    delta: &Duration,
+) -> GameResult {
+    let controller = &state.controller;
+    let player = &mut state.players[idx];
+
+    let mut operations = controller.operate(&mut player.game, &mut player.input_cache);
+    let has_put = operations.iter().any(|op| matches!(op, Operation::Put(_)));
+    if !has_put {
+        if let DroppedOrNothing::Dropped(Some(removed_lines)) = player.game.elapse(*delta) {
+            operations.push(Operation::Put(removed_lines));
+        }
+    }
+
+    for operation in operations {
+        match operation {
+            Operation::Moved => asset.audio.play_se(ctx, Se::MinoMove)?,
+            Operation::Spun => asset.audio.play_se(ctx, Se::MinoSpin)?,
+            Operation::SoftDropped => asset.audio.play_se(ctx, Se::MinoSoftDrop)?,
+            Operation::Held => (),
+            Operation::Put(removed_lines) => {
+                asset.audio.play_se(ctx, Se::MinoHardDrop)?;
+
+                if !player.game.put_and_spawn() {
+                    player.topped_out = true;
+                }
+                if !removed_lines.is_empty() {
+                    asset.audio.play_se(ctx, Se::RemoveLine)?;
+                    player.game.remove_lines();
+                }
+            }
+        }
+    }
+
+    let mut sent = 0;
+    for event in player.game.take_events() {
+        match event {
+            GameEvent::Put {
+                reward: Some(reward),
+                ..
+            } => {
+                let attack = state.attack_table.attack(&reward);
+                sent += player.garbage.offset(attack);
+            }
+            GameEvent::Put { reward: None, .. } => {
+                for lines in player.garbage.take() {
+                    let hole_x = rand::thread_rng().gen_range(0..FIELD_UNIT_WIDTH);
+                    if !player.game.raise_garbage(lines, hole_x) {
+                        player.topped_out = true;
+                    }
+                }
+            }
+            GameEvent::ToppedOut => {
+                player.topped_out = true;
+            }
+            GameEvent::Held { .. } | GameEvent::RanOut => (),
+        }
+    }
+
+    let opponent = &mut state.players[1 - idx];
+    opponent.garbage.receive(sent);
+
+    Ok(())
+}
+
+/// ラウンドの勝敗を決める. 同時に負けたときは引き分けとし, どちらにも勝利を数えない.
+fn finish_round(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    players: &mut [Player; 2],
+    losers: &[bool],
+) -> GameResult<Phase> {
+    asset.audio.stop_bgm();
+
+    let winner = match losers {
+        [true, false] => Some(1),
+        [false, true] => Some(0),
+        _ => None,
+    };
+
+    if let Some(winner) = winner {
+        players[winner].wins += 1;
+
+        if players[winner].wins >= WINS_TO_MATCH {
+            asset.audio.play_bgm(ctx, Bgm::Title)?;
+            return Ok(Phase::MatchOver { winner });
+        }
+    }
+
+    Ok(Phase::RoundOver {
+        winner,
+        timer: Timer::single(ROUND_INTERVAL),
+    })
+}
+
+pub fn draw(ctx: &mut Context, state: &VersusState, asset: &mut Asset) -> GameResult {
+    graphics::clear(ctx, asset.color.background);
+
+    for (idx, player) in state.players.iter().enumerate() {
+        draw_player(ctx, asset, player, idx, &state.phase)?;
+    }
+
+    match state.phase {
+        Phase::Countdown { remaining, .. } => {
+            renderer::draw_count_down(ctx, asset, remaining)?;
+        }
+        Phase::Playing => (),
+        Phase::RoundOver { winner, .. } => {
+            let text = match winner {
+                Some(winner) => format!("P{} WINS THE ROUND", winner + 1),
+                None => "DRAW".to_owned(),
+            };
+            renderer::draw_result(ctx, asset, &text, &[])?;
+        }
+        Phase::MatchOver { winner } => {
+            let text = format!("P{} WINS", winner + 1);
+            renderer::draw_result(ctx, asset, &text, &["ENTER: REMATCH / BACK: TITLE"])?;
+        }
+    }
+
+    Ok(())
+}
+
+fn draw_player(
+    ctx: &mut Context,
+    asset: &mut Asset,
+    player: &Player,
+    idx: usize,
+    phase: &Phase,
+) -> GameResult {
+    let layout = &player.layout;
+    let game = &player.game;
+
+    renderer::draw_field(ctx, asset, layout)?;
+    renderer::draw_garbage_meter(ctx, layout, player.garbage.pending())?;
+
+    if !matches!(phase, Phase::Countdown { .. }) {
+        renderer::draw_hold_panel(ctx, asset, layout, game.hold_mino)?;
+        renderer::draw_next_panel(
+            ctx,
+            asset,
+            layout,
+            game.bag.peek(VISIBLE_NEXT_MINO_AMOUNT).as_slice(),
+        )?;
+        renderer::draw_minos(ctx, asset, layout, &game.board, !player.topped_out)?;
+
+        if matches!(phase, Phase::Playing) {
+            renderer::draw_ghost(ctx, layout, &game.board)?;
+        }
+    }
+
+    let wins = (0..WINS_TO_MATCH)
+        .map(|n| if n < player.wins { '*' } else { '-' })
+        .collect::<String>();
+    renderer::draw_label(ctx, asset, layout, &format!("P{}  {}", idx + 1, wins))
+}
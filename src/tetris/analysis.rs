@@ -0,0 +1,400 @@
+use crate::tetris::board::{Board, Field, FIELD_UNIT_WIDTH};
+
+/// # 盤面の評価指標
+///
+/// 確定したフィールドから算出される, 積み上がりの形を表す指標群.
+/// ボット, ヒント, 危険表示, 試合後の分析で共通して使うことを想定している.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardAnalysis {
+    /// 各列の高さ. 最も上にあるブロックから床までのマス数. 盤面より右の列は 0 になる.
+    pub column_heights: [usize; FIELD_UNIT_WIDTH],
+
+    /// 上をブロックに覆われた空きマスの数.
+    pub holes: usize,
+
+    /// 穴の上に積まれているブロックの数.
+    pub covered_cells: usize,
+
+    /// 隣り合う列の高さの差の総和.
+    pub bumpiness: usize,
+
+    /// 横方向に ブロック/空き が切り替わる回数. 壁はブロックとして数える.
+    pub row_transitions: usize,
+
+    /// 縦方向に ブロック/空き が切り替わる回数. 床はブロックとして数える.
+    pub column_transitions: usize,
+
+    /// 各列の井戸の深さ. 両隣(壁を含む)より低い分だけが深さとなる.
+    pub well_depths: [usize; FIELD_UNIT_WIDTH],
+
+    /// Tスピンダブル/シングルを狙えるTミノの置き場所の候補.
+    pub t_slots: Vec<TSlot>,
+
+    /// 井戸に I ミノを入れるとテトリスになる状態かどうか.
+    pub tetris_ready: bool,
+}
+
+/// # Tスロット
+///
+/// 逆さ向きのTミノが入る空間を, その中心のマスで表現する.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TSlot {
+    pub x: usize,
+    pub y: usize,
+
+    /// Tミノを入れたときに消えるライン数.
+    pub lines: usize,
+}
+
+impl BoardAnalysis {
+    /// 盤面の大きさに収まる範囲だけを評価する.
+    pub fn of(board: &Board) -> BoardAnalysis {
+        BoardAnalysis::analyze(&Area {
+            field: &board.confirmed_field,
+            width: board.width(),
+            height: board.height(),
+        })
+    }
+
+    fn analyze(area: &Area) -> BoardAnalysis {
+        let column_heights = column_heights(area);
+
+        BoardAnalysis {
+            column_heights,
+            holes: holes(area, &column_heights),
+            covered_cells: covered_cells(area, &column_heights),
+            bumpiness: bumpiness(area, &column_heights),
+            row_transitions: row_transitions(area, &column_heights),
+            column_transitions: column_transitions(area),
+            well_depths: well_depths(area, &column_heights),
+            t_slots: t_slots(area),
+            tetris_ready: tetris_ready(area, &column_heights),
+        }
+    }
+
+    pub fn max_height(&self) -> usize {
+        self.column_heights.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn aggregate_height(&self) -> usize {
+        self.column_heights.iter().sum()
+    }
+
+    pub fn deepest_well(&self) -> Option<usize> {
+        (0..FIELD_UNIT_WIDTH)
+            .filter(|&x| self.well_depths[x] > 0)
+            .max_by_key(|&x| self.well_depths[x])
+    }
+}
+
+/// フィールドのうち, 左上から `width` x `height` の遊べる範囲.
+struct Area<'a> {
+    field: &'a Field,
+    width: usize,
+    height: usize,
+}
+
+impl Area<'_> {
+    fn is_filled(&self, x: usize, y: usize) -> bool {
+        !self.field[y][x].is_air()
+    }
+
+    fn top_y(&self, height: usize) -> usize {
+        self.height - height
+    }
+}
+
+fn column_heights(area: &Area) -> [usize; FIELD_UNIT_WIDTH] {
+    let mut heights = [0; FIELD_UNIT_WIDTH];
+
+    for (x, height) in heights.iter_mut().enumerate().take(area.width) {
+        *height = (0..area.height)
+            .find(|&y| area.is_filled(x, y))
+            .map(|y| area.height - y)
+            .unwrap_or(0);
+    }
+
+    heights
+}
+
+fn holes(area: &Area, heights: &[usize; FIELD_UNIT_WIDTH]) -> usize {
+    (0..area.width)
+        .map(|x| {
+            (area.top_y(heights[x])..area.height)
+                .filter(|&y| !area.is_filled(x, y))
+                .count()
+        })
+        .sum()
+}
+
+fn covered_cells(area: &Area, heights: &[usize; FIELD_UNIT_WIDTH]) -> usize {
+    (0..area.width)
+        .map(|x| {
+            let lowest_hole = (area.top_y(heights[x])..area.height)
+                .rev()
+                .find(|&y| !area.is_filled(x, y));
+
+            match lowest_hole {
+                Some(hole_y) => (area.top_y(heights[x])..hole_y)
+                    .filter(|&y| area.is_filled(x, y))
+                    .count(),
+                None => 0,
+            }
+        })
+        .sum()
+}
+
+fn bumpiness(area: &Area, heights: &[usize; FIELD_UNIT_WIDTH]) -> usize {
+    heights[..area.width]
+        .windows(2)
+        .map(|pair| (pair[0] as isize - pair[1] as isize).unsigned_abs())
+        .sum()
+}
+
+fn row_transitions(area: &Area, heights: &[usize; FIELD_UNIT_WIDTH]) -> usize {
+    let max_height = heights.iter().copied().max().unwrap_or(0);
+
+    (area.top_y(max_height)..area.height)
+        .map(|y| {
+            let mut transitions = 0;
+            let mut before = true;
+            for x in 0..area.width {
+                let filled = area.is_filled(x, y);
+                if filled != before {
+                    transitions += 1;
+                }
+                before = filled;
+            }
+
+            if !before {
+                transitions += 1;
+            }
+
+            transitions
+        })
+        .sum()
+}
+
+fn column_transitions(area: &Area) -> usize {
+    (0..area.width)
+        .map(|x| {
+            let mut transitions = 0;
+            let mut before = false;
+            for y in 0..area.height {
+                let filled = area.is_filled(x, y);
+                if filled != before {
+                    transitions += 1;
+                }
+                before = filled;
+            }
+
+            if !before {
+                transitions += 1;
+            }
+
+            transitions
+        })
+        .sum()
+}
+
+fn well_depths(area: &Area, heights: &[usize; FIELD_UNIT_WIDTH]) -> [usize; FIELD_UNIT_WIDTH] {
+    let mut depths = [0; FIELD_UNIT_WIDTH];
+    let heights = &heights[..area.width];
+
+    for (x, depth) in depths.iter_mut().enumerate().take(area.width) {
+        let left = x.checked_sub(1).map(|l| heights[l]).unwrap_or(usize::MAX);
+        let right = heights.get(x + 1).copied().unwrap_or(usize::MAX);
+
+        *depth = left.min(right).saturating_sub(heights[x]);
+    }
+
+    depths
+}
+
+fn t_slots(area: &Area) -> Vec<TSlot> {
+    let mut slots = Vec::new();
+
+    for y in 1..(area.height - 1) {
+        for x in 1..(area.width - 1) {
+            let opened = !area.is_filled(x - 1, y)
+                && !area.is_filled(x, y)
+                && !area.is_filled(x + 1, y)
+                && !area.is_filled(x, y + 1)
+                && !area.is_filled(x, y - 1);
+            let supported = area.is_filled(x - 1, y + 1) && area.is_filled(x + 1, y + 1);
+            let roofed = area.is_filled(x - 1, y - 1) || area.is_filled(x + 1, y - 1);
+
+            if opened && supported && roofed {
+                let upper_cleared = (0..area.width)
+                    .filter(|&lx| lx + 1 < x || x + 1 < lx)
+                    .all(|lx| area.is_filled(lx, y));
+                let lower_cleared = (0..area.width)
+                    .filter(|&lx| lx != x)
+                    .all(|lx| area.is_filled(lx, y + 1));
+
+                slots.push(TSlot {
+                    x,
+                    y,
+                    lines: upper_cleared as usize + lower_cleared as usize,
+                });
+            }
+        }
+    }
+
+    slots
+}
+
+fn tetris_ready(area: &Area, heights: &[usize; FIELD_UNIT_WIDTH]) -> bool {
+    (0..area.width).any(|x| {
+        let well_bottom = area.top_y(heights[x]);
+        if well_bottom < 4 {
+            return false;
+        }
+
+        ((well_bottom - 4)..well_bottom).all(|y| {
+            (0..area.width)
+                .filter(|&lx| lx != x)
+                .all(|lx| area.is_filled(lx, y))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::tetris::{
+        board::FIELD_UNIT_HEIGHT,
+        model::{mino_entity::MinoEntity, tetrimino::Tetrimino},
+    };
+
+    /// 最後の行が床に接するように, `#` をブロック, `.` を空きとしてフィールドを作る.
+    fn field_from_bottom(lines: &[&str]) -> Field {
+        let mut field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+
+        for (idx, line) in lines.iter().rev().enumerate() {
+            let y = FIELD_UNIT_HEIGHT - 1 - idx;
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    field[y][x] = MinoEntity::RED;
+                }
+            }
+        }
+
+        field
+    }
+
+    /// フィールド全体を盤面として評価する.
+    fn of_field(field: &Field) -> BoardAnalysis {
+        BoardAnalysis::analyze(&Area {
+            field,
+            width: FIELD_UNIT_WIDTH,
+            height: FIELD_UNIT_HEIGHT,
+        })
+    }
+
+    #[test]
+    fn test_empty_field() {
+        let analysis = of_field(&field_from_bottom(&[]));
+
+        assert_eq!(analysis.column_heights, [0; FIELD_UNIT_WIDTH]);
+        assert_eq!(analysis.holes, 0);
+        assert_eq!(analysis.covered_cells, 0);
+        assert_eq!(analysis.bumpiness, 0);
+        assert_eq!(analysis.row_transitions, 0);
+        assert_eq!(analysis.column_transitions, FIELD_UNIT_WIDTH);
+        assert!(analysis.t_slots.is_empty());
+        assert!(!analysis.tetris_ready);
+    }
+
+    #[test]
+    fn test_heights_holes_and_covered_cells() {
+        let analysis = of_field(&field_from_bottom(&[
+            "#.........",
+            "#.#.......",
+            "..##......",
+        ]));
+
+        assert_eq!(analysis.column_heights, [3, 0, 2, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(analysis.holes, 1);
+        assert_eq!(analysis.covered_cells, 2);
+        assert_eq!(analysis.bumpiness, 3 + 2 + 1 + 1);
+        assert_eq!(analysis.max_height(), 3);
+        assert_eq!(analysis.aggregate_height(), 6);
+    }
+
+    #[test]
+    fn test_ignores_outside_of_small_board() {
+        let mut board = Board::with_size(Tetrimino::O, 5, 11);
+        for x in 0..4 {
+            board.confirmed_field[10][x] = MinoEntity::RED;
+        }
+        // 盤面の外に残ったブロックは数えない
+        board.confirmed_field[FIELD_UNIT_HEIGHT - 1][7] = MinoEntity::RED;
+
+        let analysis = BoardAnalysis::of(&board);
+
+        assert_eq!(analysis.column_heights, [1, 1, 1, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(analysis.holes, 0);
+        assert_eq!(analysis.bumpiness, 1);
+        assert_eq!(analysis.row_transitions, 2);
+        assert_eq!(analysis.column_transitions, 5);
+        assert_eq!(analysis.deepest_well(), Some(4));
+    }
+
+    #[test]
+    fn test_transitions() {
+        let analysis = of_field(&field_from_bottom(&["#.#######.", "#.#.######"]));
+
+        assert_eq!(analysis.row_transitions, 8);
+        assert_eq!(analysis.column_transitions, FIELD_UNIT_WIDTH + 2);
+    }
+
+    #[test_case(&["#########."], 9, 1)]
+    #[test_case(&["..#.......", "#.#.......", "#.#......."], 1, 2)]
+    #[test_case(&["##.#######"], 2, 1)]
+    fn test_well_depths(lines: &[&str], well_x: usize, depth: usize) {
+        let analysis = of_field(&field_from_bottom(lines));
+
+        assert_eq!(analysis.well_depths[well_x], depth);
+        assert_eq!(analysis.deepest_well(), Some(well_x));
+    }
+
+    #[test]
+    fn test_tetris_ready() {
+        let ready = of_field(&field_from_bottom(&[
+            "#########.",
+            "#########.",
+            "#########.",
+            "#########.",
+        ]));
+        let not_ready = of_field(&field_from_bottom(&[
+            "#########.",
+            "#########.",
+            "########..",
+            "#########.",
+        ]));
+
+        assert!(ready.tetris_ready);
+        assert!(!not_ready.tetris_ready);
+    }
+
+    #[test]
+    fn test_t_slot_for_t_spin_double() {
+        let analysis = of_field(&field_from_bottom(&[
+            "##........",
+            "#...######",
+            "##.#######",
+        ]));
+
+        assert_eq!(
+            analysis.t_slots,
+            vec![TSlot {
+                x: 2,
+                y: FIELD_UNIT_HEIGHT - 2,
+                lines: 2,
+            }]
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::VecDeque;
+
+use crate::tetris::model::score::{ScoringAction, ScoringReward};
+
+/// # 攻撃テーブル
+///
+/// ラインを消したときの報酬を, 相手に送るおじゃまラインの数に変換する.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackTable {
+    pub single: usize,
+    pub double: usize,
+    pub triple: usize,
+    pub tetris: usize,
+    pub t_spin_single: usize,
+    pub t_spin_double: usize,
+    pub t_spin_triple: usize,
+    pub perfect_clear: usize,
+
+    pub bonus: AttackBonus,
+}
+
+/// # 攻撃の加算
+///
+/// Back to Back とコンボによって, 消し方ごとのライン数に上乗せする方法.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttackBonus {
+    Table {
+        /// Back to Back が成立したときに加算されるライン数.
+        back_to_back: usize,
+
+        /// n番目の要素が, n連続コンボのときに加算されるライン数となる.
+        /// テーブルの長さを超えたコンボは最後の要素が使われる.
+        combo: Vec<usize>,
+    },
+
+    /// TETR.IO の計算. Back to Back は続くほど加算が増え, コンボは攻撃に倍率を掛ける.
+    /// 端数は最後に切り捨てる.
+    Tetrio,
+}
+
+impl AttackTable {
+    /// ガイドラインに準拠したテーブル.
+    pub fn guideline() -> AttackTable {
+        AttackTable {
+            single: 0,
+            double: 1,
+            triple: 2,
+            tetris: 4,
+            t_spin_single: 2,
+            t_spin_double: 4,
+            t_spin_triple: 6,
+            perfect_clear: 10,
+            bonus: AttackBonus::Table {
+                back_to_back: 1,
+                combo: vec![0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 4, 5],
+            },
+        }
+    }
+
+    /// TETR.IO のテーブル. 消し方ごとのライン数はガイドラインと同じ.
+    pub fn tetrio() -> AttackTable {
+        AttackTable {
+            bonus: AttackBonus::Tetrio,
+            ..AttackTable::guideline()
+        }
+    }
+
+    pub fn action_lines(&self, action: &ScoringAction) -> usize {
+        match action {
+            ScoringAction::Single => self.single,
+            ScoringAction::Double => self.double,
+            ScoringAction::Triple => self.triple,
+            ScoringAction::Tetris => self.tetris,
+            ScoringAction::TSpinSingle => self.t_spin_single,
+            ScoringAction::TSpinDouble => self.t_spin_double,
+            ScoringAction::TSpinTriple => self.t_spin_triple,
+            ScoringAction::PerfectClear => self.perfect_clear,
+        }
+    }
+
+    pub fn attack(&self, reward: &ScoringReward) -> usize {
+        let lines = self.action_lines(&reward.action);
+        // 直前まで Back to Back の対象になる消し方が続いた回数
+        let back_to_back = if reward.action.is_subjected_to_back_to_back() {
+            reward.back_to_back
+        } else {
+            0
+        };
+        // 最初にラインを消したときを0とするコンボ数
+        let combo = reward.combo.saturating_sub(1);
+
+        match self.bonus {
+            AttackBonus::Table {
+                back_to_back: back_to_back_bonus,
+                combo: ref combo_table,
+            } => {
+                let back_to_back_bonus = if back_to_back > 0 {
+                    back_to_back_bonus
+                } else {
+                    0
+                };
+                let combo_bonus = combo_table
+                    .get(combo)
+                    .or_else(|| combo_table.last())
+                    .copied()
+                    .unwrap_or(0);
+
+                lines + back_to_back_bonus + combo_bonus
+            }
+            AttackBonus::Tetrio => tetrio_attack(lines, back_to_back, combo),
+        }
+    }
+}
+
+fn tetrio_attack(lines: usize, back_to_back: usize, combo: usize) -> usize {
+    let mut attack = lines as f64;
+
+    if back_to_back > 0 {
+        let chain = (back_to_back as f64 * 0.8).ln_1p();
+        attack += (1. + chain).floor();
+        if back_to_back > 1 {
+            attack += (1. + chain.fract()) / 3.;
+        }
+    }
+
+    let combo = combo as f64;
+    attack *= 1. + 0.25 * combo;
+    // 攻撃の少ない消し方でも, コンボが続けば攻撃になる
+    if combo > 1. {
+        attack = attack.max((1.25 * combo).ln_1p());
+    }
+
+    attack.floor() as usize
+}
+
+impl Default for AttackTable {
+    fn default() -> Self {
+        AttackTable::guideline()
+    }
+}
+
+/// # 受け取ったおじゃまライン
+///
+/// まだ盤面にせり上がっていないおじゃまラインを, 受け取った攻撃ごとに保持する.
+/// 自分の攻撃で相殺することができる.
+#[derive(Debug, Default)]
+pub struct IncomingGarbage {
+    queue: VecDeque<usize>,
+}
+
+impl IncomingGarbage {
+    pub fn new() -> IncomingGarbage {
+        IncomingGarbage::default()
+    }
+
+    pub fn receive(&mut self, lines: usize) {
+        if lines > 0 {
+            self.queue.push_back(lines);
+        }
+    }
+
+    /// 古いものから順に攻撃で相殺し, 相殺しきれなかった攻撃のライン数を返す.
+    pub fn offset(&mut self, attack: usize) -> usize {
+        let mut attack = attack;
+
+        while attack > 0 {
+            match self.queue.front_mut() {
+                Some(lines) if *lines > attack => {
+                    *lines -= attack;
+                    attack = 0;
+                }
+                Some(lines) => {
+                    attack -= *lines;
+                    self.queue.pop_front();
+                }
+                None => break,
+            }
+        }
+
+        attack
+    }
+
+    /// せり上がりを待っているライン数.
+    pub fn pending(&self) -> usize {
+        self.queue.iter().sum()
+    }
+
+    /// 待っていたおじゃまラインを全て取り出す. 要素は受け取った攻撃ごとのライン数.
+    pub fn take(&mut self) -> Vec<usize> {
+        self.queue.drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(ScoringAction::Single, 0, 1, 0)]
+    #[test_case(ScoringAction::Tetris, 0, 1, 4)]
+    #[test_case(ScoringAction::Tetris, 1, 1, 5)]
+    #[test_case(ScoringAction::Tetris, 3, 1, 5)]
+    #[test_case(ScoringAction::Double, 1, 1, 1)]
+    #[test_case(ScoringAction::TSpinDouble, 1, 3, 6)]
+    #[test_case(ScoringAction::Single, 0, 30, 5)]
+    fn test_guideline_attack(action: ScoringAction, b2b: usize, combo: usize, lines: usize) {
+        let reward = ScoringReward::new(action, b2b, combo);
+
+        assert_eq!(AttackTable::guideline().attack(&reward), lines);
+    }
+
+    #[test_case(ScoringAction::Single, 0, 1, 0)]
+    #[test_case(ScoringAction::Tetris, 0, 1, 4)]
+    #[test_case(ScoringAction::Tetris, 1, 1, 5)]
+    #[test_case(ScoringAction::TSpinDouble, 2, 1, 5)]
+    #[test_case(ScoringAction::Tetris, 4, 1, 6)]
+    #[test_case(ScoringAction::Tetris, 0, 2, 5)]
+    #[test_case(ScoringAction::Double, 0, 5, 2)]
+    #[test_case(ScoringAction::Single, 0, 5, 1)]
+    #[test_case(ScoringAction::Single, 0, 10, 2)]
+    fn test_tetrio_attack(action: ScoringAction, b2b: usize, combo: usize, lines: usize) {
+        let reward = ScoringReward::new(action, b2b, combo);
+
+        assert_eq!(AttackTable::tetrio().attack(&reward), lines);
+    }
+
+    #[test]
+    fn test_offset_cancels_oldest_first() {
+        let mut garbage = IncomingGarbage::new();
+        garbage.receive(2);
+        garbage.receive(3);
+
+        assert_eq!(garbage.offset(3), 0);
+        assert_eq!(garbage.pending(), 2);
+        assert_eq!(garbage.take(), vec![2]);
+        assert_eq!(garbage.pending(), 0);
+    }
+
+    #[test]
+    fn test_offset_returns_surplus() {
+        let mut garbage = IncomingGarbage::new();
+        garbage.receive(1);
+
+        assert_eq!(garbage.offset(4), 3);
+        assert_eq!(garbage.pending(), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, convert::TryFrom};
+use std::{collections::VecDeque, convert::TryFrom, time::Duration};
 
 use crate::tetris::{
     game::Point,
@@ -14,6 +14,8 @@ pub const FIELD_UNIT_HEIGHT: usize = 21;
 pub const FIELD_VISIBLE_UNIT_HEIGHT: usize = 20;
 
 pub type Field = [[MinoEntity; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+/// 各マスにブロックが置かれた, ゲーム開始からの時刻.
+pub type LockTimes = [[Duration; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
 pub type RemovedLines = Vec<usize>;
 
 pub const SPAWN_POINT: Point = Point { x: 4, y: 1 };
@@ -24,14 +26,35 @@ pub struct Board {
     pub dropping: Tetrimino,
     dropping_point: Point,
     dropping_rotation: MinoRotation,
+    /// false のときは, その場で回転できないと回転に失敗する.
+    pub wall_kicks: bool,
+    /// ラインを消したりせり上げたりすると, ブロックと一緒に動く.
+    pub locked_at: LockTimes,
+    /// ミノを動かせる範囲. `Field` の左上からこの大きさの部分だけを使う.
+    width: usize,
+    height: usize,
 }
 
 impl Board {
     pub fn new(dropping: Tetrimino) -> Board {
-        Board::new_with_field(
+        Board::with_size(dropping, FIELD_UNIT_WIDTH, FIELD_UNIT_HEIGHT)
+    }
+
+    /// `Field` より狭い範囲だけで遊ぶ盤面. 大きさは `Field` を超えてはならない.
+    pub fn with_size(dropping: Tetrimino, width: usize, height: usize) -> Board {
+        if FIELD_UNIT_WIDTH < width || FIELD_UNIT_HEIGHT < height {
+            panic!("the board must fit in the field");
+        }
+
+        let mut board = Board::new_with_field(
             dropping,
             [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT],
-        )
+        );
+        board.width = width;
+        board.height = height;
+        board.dropping_point = board.spawn_point();
+
+        board
     }
 
     fn new_with_field(dropping: Tetrimino, field: Field) -> Board {
@@ -40,6 +63,26 @@ impl Board {
             dropping,
             dropping_point: SPAWN_POINT,
             dropping_rotation: MinoRotation::default(),
+            wall_kicks: true,
+            locked_at: [[Duration::ZERO; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT],
+            width: FIELD_UNIT_WIDTH,
+            height: FIELD_UNIT_HEIGHT,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// 盤面の幅に合わせて, 中央に寄せた出現位置.
+    fn spawn_point(&self) -> Point {
+        Point {
+            x: ((self.width - 1) / 2) as isize,
+            y: SPAWN_POINT.y,
         }
     }
 
@@ -67,7 +110,7 @@ impl Board {
 
     pub fn spawn(&mut self, dropping: Tetrimino) -> bool {
         self.dropping = dropping;
-        self.dropping_point = SPAWN_POINT;
+        self.dropping_point = self.spawn_point();
         self.dropping_rotation = MinoRotation::Clockwise;
 
         self.establishes_field()
@@ -96,8 +139,8 @@ impl Board {
             board.dropping_point.y += offset.y;
         }
 
-        let offset = (0..5)
-            .into_iter()
+        let tries = if self.wall_kicks { 5 } else { 1 };
+        let offset = (0..tries)
             .flat_map(|idx| {
                 let offsets = self
                     .dropping
@@ -165,9 +208,10 @@ impl Board {
         n
     }
 
-    pub fn determine_dropping_mino(&mut self) {
+    pub fn determine_dropping_mino(&mut self, at: Duration) {
         for p in self.dropping_mino_points() {
             self.confirmed_field[p.y as usize][p.x as usize] = self.dropping.block().into();
+            self.locked_at[p.y as usize][p.x as usize] = at;
         }
     }
 
@@ -199,14 +243,56 @@ impl Board {
             }
         }
 
+        let kept_lines = (0..FIELD_UNIT_HEIGHT).filter(|y| !removed_lines.contains(y));
+        let mut locked_at = [[Duration::ZERO; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+        for (y, kept) in (removed_lines.len()..FIELD_UNIT_HEIGHT).zip(kept_lines) {
+            locked_at[y] = self.locked_at[kept];
+        }
+        self.locked_at = locked_at;
+
         removed_lines.len()
     }
 
+    /// 一番下に `hole_x` の列だけが空いたおじゃまラインを1行せり上げる.
+    /// 押し出されたブロックが溢れたり, 落下中のミノが逃げられなかったときは false を返す.
+    pub fn raise_garbage(&mut self, hole_x: usize) -> bool {
+        let overflowed = self.confirmed_field[0]
+            .iter()
+            .any(|entity| !entity.is_air());
+
+        let bottom = self.height - 1;
+        for y in 0..bottom {
+            self.confirmed_field[y] = self.confirmed_field[y + 1];
+            self.locked_at[y] = self.locked_at[y + 1];
+        }
+        self.locked_at[bottom] = [Duration::ZERO; FIELD_UNIT_WIDTH];
+
+        let mut garbage = [MinoEntity::AIR; FIELD_UNIT_WIDTH];
+        for (x, entity) in garbage.iter_mut().enumerate().take(self.width) {
+            if x != hole_x % self.width {
+                *entity = MinoEntity::GARBAGE;
+            }
+        }
+        self.confirmed_field[bottom] = garbage;
+
+        if !self.establishes_field() {
+            self.dropping_point.y -= 1;
+        }
+
+        !overflowed && self.establishes_field()
+    }
+
+    pub fn clear_stack(&mut self) {
+        self.confirmed_field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+        self.locked_at = [[Duration::ZERO; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+    }
+
     pub fn filled_lines(&self) -> RemovedLines {
         self.field()
             .iter()
             .enumerate()
-            .filter(|(_, line)| line.iter().all(|entity| !entity.is_air()))
+            .take(self.height)
+            .filter(|(_, line)| line[..self.width].iter().all(|entity| !entity.is_air()))
             .map(|(y, _)| y)
             .collect::<Vec<_>>()
     }
@@ -259,6 +345,10 @@ impl Board {
         self.dropping_mino_points().iter().all(|&point| {
             if let Ok(x) = usize::try_from(point.x) {
                 if let Ok(y) = usize::try_from(point.y) {
+                    if self.width <= x || self.height <= y {
+                        return false;
+                    }
+
                     let entity = self.confirmed_field.get(y).and_then(|line| line.get(x));
 
                     if let Some(entity) = entity {
@@ -271,3 +361,53 @@ impl Board {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_times_move_with_removed_lines() {
+        let mut board = Board::new(Tetrimino::O);
+        let bottom = FIELD_UNIT_HEIGHT - 1;
+        board.confirmed_field[bottom] = [MinoEntity::GARBAGE; FIELD_UNIT_WIDTH];
+        board.confirmed_field[bottom - 1][0] = MinoEntity::GARBAGE;
+        board.locked_at[bottom - 1][0] = Duration::from_secs(3);
+
+        assert_eq!(board.remove_lines(), 1);
+        assert_eq!(board.locked_at[bottom][0], Duration::from_secs(3));
+        assert_eq!(board.locked_at[bottom - 1][0], Duration::ZERO);
+    }
+
+    #[test]
+    fn test_narrow_board_limits_moves_and_lines() {
+        let mut board = Board::with_size(Tetrimino::O, 5, 11);
+
+        while board.try_move_x(1) {}
+        assert!(board.dropping_mino_points().iter().all(|p| p.x < 5));
+
+        board.hard_drop();
+        assert!(board
+            .dropping_mino_points()
+            .iter()
+            .all(|p| p.y == 9 || p.y == 10));
+
+        board.confirmed_field[10] = [MinoEntity::GARBAGE; FIELD_UNIT_WIDTH];
+        board.confirmed_field[10][7] = MinoEntity::AIR;
+        assert_eq!(board.filled_lines(), vec![10]);
+    }
+
+    #[test]
+    fn test_records_lock_time_of_determined_mino() {
+        let mut board = Board::new(Tetrimino::O);
+        board.hard_drop();
+        board.determine_dropping_mino(Duration::from_secs(5));
+
+        for p in board.dropping_mino_points() {
+            assert_eq!(
+                board.locked_at[p.y as usize][p.x as usize],
+                Duration::from_secs(5)
+            );
+        }
+    }
+}
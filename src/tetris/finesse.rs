@@ -0,0 +1,161 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::tetris::{board::Board, model::spin::SpinDirection};
+
+/// これより多くの入力が必要な位置は探さない. どの位置も4回以内で置ける.
+const MAX_INPUTS: usize = 6;
+
+/// # フィネス
+///
+/// 置いたミノの入力数を, 何もない盤面で同じ位置に置くための最小の入力数と比べる.
+/// 押しっぱなしで壁まで動かすのは, 1回の入力として数える.
+#[derive(Debug, Default)]
+pub struct FinesseTracker {
+    inputs: usize,
+    /// ソフトドロップを使ったミノは, 隙間に入れた可能性があるので判定しない.
+    soft_dropped: bool,
+}
+
+impl FinesseTracker {
+    pub fn new() -> FinesseTracker {
+        FinesseTracker::default()
+    }
+
+    /// 移動か回転のために押した入力を数える.
+    pub fn count_inputs(&mut self, inputs: usize) {
+        self.inputs += inputs;
+    }
+
+    pub fn count_soft_drop(&mut self) {
+        self.soft_dropped = true;
+    }
+
+    /// ホールドしたときなど, 新しいミノを操作し始めるときに数え直す.
+    pub fn reset(&mut self) {
+        *self = FinesseTracker::new();
+    }
+
+    /// `board` の落下中のミノを置いた位置で, 入力が多すぎたかを判定して数え直す.
+    pub fn judge(&mut self, board: &Board) -> bool {
+        let is_fault = !self.soft_dropped
+            && optimal_inputs(board).is_some_and(|optimal| optimal < self.inputs);
+        self.reset();
+
+        is_fault
+    }
+}
+
+/// 何もない盤面で, 出現位置から `board` の落下中のミノと同じ位置に置くための最小の入力数.
+pub fn optimal_inputs(board: &Board) -> Option<usize> {
+    let target = placement_key(board);
+
+    let mut start = Board::with_size(board.dropping, board.width(), board.height());
+    start.wall_kicks = board.wall_kicks;
+
+    let mut visited = HashSet::new();
+    visited.insert(placement_key(&start));
+    let mut queue = VecDeque::from(vec![(start, 0)]);
+
+    while let Some((current, inputs)) = queue.pop_front() {
+        if placement_key(&current) == target {
+            return Some(inputs);
+        }
+        if MAX_INPUTS <= inputs {
+            continue;
+        }
+
+        for next in neighbors(&current) {
+            if visited.insert(placement_key(&next)) {
+                queue.push_back((next, inputs + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// 1回の入力で移れる位置.
+fn neighbors(board: &Board) -> Vec<Board> {
+    let tap = |addition: isize| {
+        let mut board = *board;
+        board.try_move_x(addition).then_some(board)
+    };
+    let hold = |addition: isize| {
+        let mut board = *board;
+        let mut moved = false;
+        while board.try_move_x(addition) {
+            moved = true;
+        }
+        moved.then_some(board)
+    };
+    let spin = |direction: SpinDirection| {
+        let mut board = *board;
+        board.try_spin(direction).map(|_| board)
+    };
+
+    vec![
+        tap(-1),
+        tap(1),
+        hold(-1),
+        hold(1),
+        spin(SpinDirection::Left),
+        spin(SpinDirection::Right),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// 真下に落としたときに埋まるマスの, 列と形. 同じ形になる向きは区別しない.
+fn placement_key(board: &Board) -> Vec<(isize, isize)> {
+    let mut dropped = *board;
+    dropped.confirmed_field = Board::new(board.dropping).confirmed_field;
+    dropped.hard_drop();
+
+    let points = dropped.dropping_mino_points();
+    let top = points.iter().map(|p| p.y).min().unwrap_or(0);
+
+    let mut key: Vec<_> = points.iter().map(|p| (p.x, p.y - top)).collect();
+    key.sort_unstable();
+
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::tetris::model::tetrimino::Tetrimino;
+
+    fn placed(mino: Tetrimino, operate: impl Fn(&mut Board)) -> Board {
+        let mut board = Board::new(mino);
+        operate(&mut board);
+
+        board
+    }
+
+    #[test_case(Tetrimino::T, |_| (), 0; "at the spawn point")]
+    #[test_case(Tetrimino::T, |b| while b.try_move_x(-1) {}, 1; "to the left wall")]
+    #[test_case(Tetrimino::I, |b| { b.try_spin(SpinDirection::Right); }, 1; "spin once")]
+    #[test_case(Tetrimino::O, |b| { b.try_move_x(1); b.try_move_x(1); }, 2; "two taps")]
+    fn test_optimal_inputs(mino: Tetrimino, operate: fn(&mut Board), expected: usize) {
+        assert_eq!(optimal_inputs(&placed(mino, operate)), Some(expected));
+    }
+
+    #[test]
+    fn test_judges_extra_inputs_as_fault() {
+        let board = placed(Tetrimino::T, |b| while b.try_move_x(-1) {});
+        let mut tracker = FinesseTracker::new();
+
+        tracker.count_inputs(1);
+        assert!(!tracker.judge(&board));
+
+        tracker.count_inputs(3);
+        assert!(tracker.judge(&board));
+
+        tracker.count_inputs(3);
+        tracker.count_soft_drop();
+        assert!(!tracker.judge(&board));
+    }
+}
@@ -1,21 +1,28 @@
-use std::time::Duration;
+use std::{mem, time::Duration};
 
 use crate::tetris::{
-    board::{Board, RemovedLines},
+    board::{Board, RemovedLines, FIELD_UNIT_HEIGHT, FIELD_UNIT_WIDTH},
     mino_bag::MinoBag,
     model::{
         score::{ScoringAction, ScoringReward},
         spin::SpinDirection,
         tetrimino::Tetrimino,
     },
+    rule::Rule,
 };
 
 const NATURAL_DROP_INTERVAL: Duration = Duration::from_secs(1);
+const LOCK_DELAY: Duration = Duration::from_secs(1);
 const COMBO_INITIAL: usize = 1;
 
+/// 大きなミノで遊ぶ盤面の大きさ. 隠れている1行と, 見えている行の半分.
+const BIG_WIDTH: usize = FIELD_UNIT_WIDTH / 2;
+const BIG_HEIGHT: usize = 1 + (FIELD_UNIT_HEIGHT - 1) / 2;
+
 pub type PutOrJustDropped = Option<RemovedLines>;
 
 pub struct Game {
+    pub rule: Rule,
     pub board: Board,
     pub bag: MinoBag,
     pub hold_mino: Option<Tetrimino>,
@@ -24,41 +31,131 @@ pub struct Game {
     pub elapsed: Duration,
     last_dropped: Duration,
 
+    /// 自然落下で1マス落ちるまでの時間. フレームより短いときは1フレームで複数マス落ちる.
+    pub drop_interval: Duration,
+    /// 接地してから操作しないまま置かれるまでの時間.
+    pub lock_delay: Duration,
+    /// ミノを置いてから次のミノを操作できるようになるまでの時間.
+    pub entry_delay: Duration,
+    entered_at: Duration,
+
     pub score: usize,
-    ready_back_to_back: bool,
+    /// ラインを消したときの得点に掛けられる倍率. レベル制のモードで使う.
+    pub score_multiplier: usize,
+    /// 直前まで Back to Back の対象になる消し方が続いた回数.
+    back_to_back: usize,
     rotated_just_before: bool,
     combo: usize,
     pub removed_line_count: usize,
+
+    events: Vec<GameEvent>,
 }
 
 impl Game {
     pub fn new() -> Game {
-        let mut bag = MinoBag::new();
-        let dropping = bag.pop();
+        Game::with_bag(MinoBag::new())
+    }
+
+    /// 同じシードのゲーム同士では, 同じ順番でミノが出現する.
+    pub fn with_seed(seed: u64) -> Game {
+        Game::with_bag(MinoBag::with_seed(seed))
+    }
+
+    pub fn with_rule(rule: Rule) -> Game {
+        Game::with_bag(MinoBag::with_randomizer(rule.randomizer)).ruled(rule)
+    }
+
+    /// 同じシードとルールのゲーム同士では, 同じ順番でミノが出現する.
+    pub fn with_seeded_rule(rule: Rule, seed: u64) -> Game {
+        Game::with_bag(MinoBag::with_seeded_randomizer(seed, rule.randomizer)).ruled(rule)
+    }
+
+    /// `sequence` のミノだけで遊ぶ. 使い切ると次のミノは出現しない.
+    pub fn with_sequence(rule: Rule, sequence: Vec<Tetrimino>) -> Game {
+        Game::with_bag(MinoBag::with_sequence(sequence)).ruled(rule)
+    }
+
+    /// `queue` のミノから始め, 使い切った後は7種1巡のミノが続く.
+    pub fn with_queue(rule: Rule, queue: Vec<Tetrimino>) -> Game {
+        Game::with_bag(MinoBag::with_queue(queue)).ruled(rule)
+    }
+
+    fn ruled(self, rule: Rule) -> Game {
+        let mut game = self;
+        if rule.big {
+            game.board = Board::with_size(game.board.dropping, BIG_WIDTH, BIG_HEIGHT);
+        }
+        game.board.wall_kicks = rule.wall_kicks;
+        game.rule = rule;
+
+        game
+    }
+
+    fn with_bag(bag: MinoBag) -> Game {
+        let mut bag = bag;
+        let dropping = bag.pop().expect("the bag must supply the first mino");
 
         Game {
+            rule: Rule::guideline(),
             board: Board::new(dropping),
             bag,
             hold_mino: None,
             did_already_hold: false,
             elapsed: Duration::ZERO,
             last_dropped: Duration::ZERO,
+            drop_interval: NATURAL_DROP_INTERVAL,
+            lock_delay: LOCK_DELAY,
+            entry_delay: Duration::ZERO,
+            entered_at: Duration::ZERO,
             score: 0,
-            ready_back_to_back: false,
+            score_multiplier: 1,
+            back_to_back: 0,
             rotated_just_before: false,
             combo: COMBO_INITIAL,
             removed_line_count: 0,
+            events: Vec::new(),
         }
     }
 
+    /// 前回取り出してから今までに起きたイベントを取り出す.
+    pub fn take_events(&mut self) -> Vec<GameEvent> {
+        mem::take(&mut self.events)
+    }
+
+    /// 今続いているコンボの数. 2回続けてラインを消すと1になる.
+    pub fn combo(&self) -> usize {
+        self.combo.saturating_sub(COMBO_INITIAL + 1)
+    }
+
+    /// 出現したミノがまだ `entry_delay` の間で, 操作も落下もしないかどうか.
+    pub fn is_entering(&self) -> bool {
+        self.elapsed < self.entered_at
+    }
+
     pub fn elapse(&mut self, delta: Duration) -> DroppedOrNothing {
         self.elapsed += delta;
+        let since_dropped = self.elapsed.saturating_sub(self.last_dropped);
+
+        if self.board.dropping_mino_is_on_ground() {
+            if self.lock_delay < since_dropped {
+                return DroppedOrNothing::Dropped(self.drop_one());
+            }
+
+            return DroppedOrNothing::Nothing;
+        }
+
+        if self.drop_interval < since_dropped {
+            let rows = since_dropped.as_nanos() / self.drop_interval.as_nanos().max(1);
+            for _ in 0..rows {
+                if !self.board.drop_one() {
+                    break;
+                }
+            }
 
-        if self.last_dropped + NATURAL_DROP_INTERVAL < self.elapsed {
             self.last_dropped = self.elapsed;
-            let result = self.drop_one();
+            self.rotated_just_before = false;
 
-            return DroppedOrNothing::Dropped(result);
+            return DroppedOrNothing::Dropped(None);
         }
 
         DroppedOrNothing::Nothing
@@ -104,13 +201,18 @@ impl Game {
         Some(self.prepare_putting().removed_lines)
     }
 
+    /// 押し下げられた行の分だけ得点を加える. 接地していれば置く.
     pub fn soft_drop(&mut self) -> PutOrJustDropped {
-        self.score += 1;
-        self.drop_one()
+        let put = self.drop_one();
+        if put.is_none() {
+            self.score += self.rule.scoring.soft_drop_score(1);
+        }
+
+        put
     }
 
     pub fn hard_drop(&mut self) -> RemovedLines {
-        self.score += 2 * self.board.hard_drop();
+        self.score += self.rule.scoring.hard_drop_score() * self.board.hard_drop();
 
         self.prepare_putting().removed_lines
     }
@@ -119,12 +221,27 @@ impl Game {
     fn prepare_putting(&mut self) -> PutResult {
         let put_result = self.calc_put_result_if_did();
 
-        self.removed_line_count += put_result.removed_lines.len();
+        let removed_lines = put_result.removed_lines.len() * self.line_weight();
+        self.removed_line_count += removed_lines;
         if let Some(ref reward) = put_result.reward {
-            self.score += reward.score();
-            self.ready_back_to_back = reward.action.is_subjected_to_back_to_back()
+            let score = self
+                .rule
+                .scoring
+                .score(reward, put_result.removed_lines.len());
+            self.score += score * self.score_multiplier;
+            self.back_to_back = if reward.action.is_subjected_to_back_to_back() {
+                self.back_to_back + 1
+            } else {
+                0
+            };
         }
 
+        self.events.push(GameEvent::Put {
+            mino: self.board.dropping,
+            removed_lines,
+            reward: put_result.reward.clone(),
+        });
+
         put_result
     }
 
@@ -134,11 +251,14 @@ impl Game {
             return PutResult::new(lines, None);
         }
 
+        // 置いたミノを含めて, 消えるライン以外に何も残らないか
         let did_perfect_clear = self
             .board
-            .confirmed_field
+            .field()
             .iter()
-            .all(|line| line.iter().all(|e| e.is_air()));
+            .enumerate()
+            .filter(|(y, _)| !lines.contains(y))
+            .all(|(_, line)| line.iter().all(|e| e.is_air()));
         let did_t_spin = self.board.dropping == Tetrimino::T
             && self.rotated_just_before
             && self.board.satisfies_cond_for_t_spin();
@@ -162,34 +282,62 @@ impl Game {
             }
         };
 
-        let reward = ScoringReward::new(action, self.ready_back_to_back, self.combo);
+        let reward = ScoringReward::new(action, self.back_to_back, self.combo);
 
-        let r = PutResult::new(lines, Some(reward));
-        println!("result: {:?}", r);
-        r
+        PutResult::new(lines, Some(reward))
     }
 
-    pub fn try_swap_hold(&mut self) {
-        if !self.did_already_hold {
-            self.last_dropped = self.elapsed;
-            self.did_already_hold = true;
+    /// 1ラインを消したときに数えるライン数. 大きなミノのときは2ライン分になる.
+    fn line_weight(&self) -> usize {
+        if self.rule.big {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// ホールドと落下中のミノを入れ替え, 入れ替えられたかどうかを返す.
+    pub fn try_swap_hold(&mut self) -> bool {
+        if !self.rule.hold || self.did_already_hold {
+            return false;
+        }
 
-            match self.hold_mino {
-                Some(spawned) => {
-                    let held = self.board.dropping;
+        // 使い切ってしまうときは, 空のホールドに入れられない
+        let spawned = match self.hold_mino {
+            Some(held) => held,
+            None => match self.bag.pop() {
+                Some(popped) => popped,
+                None => return false,
+            },
+        };
 
-                    self.hold_mino = Some(held);
-                    self.board.spawn(spawned);
-                }
-                None => {
-                    let spawned = self.bag.pop();
-                    let held = self.board.dropping;
+        self.last_dropped = self.elapsed;
+        self.did_already_hold = true;
+        self.events.push(GameEvent::Held {
+            mino: self.board.dropping,
+        });
 
-                    self.hold_mino = Some(held);
-                    self.board.spawn(spawned);
-                }
-            }
+        self.hold_mino = Some(self.board.dropping);
+        self.board.spawn(spawned);
+
+        true
+    }
+
+    /// 受け取ったおじゃまラインを, 穴の位置を揃えてせり上げる.
+    pub fn raise_garbage(&mut self, lines: usize, hole_x: usize) -> bool {
+        let survived = (0..lines).all(|_| self.board.raise_garbage(hole_x));
+        if !survived {
+            self.events.push(GameEvent::ToppedOut);
         }
+
+        survived
+    }
+
+    /// 置かれたブロックを全て消し, 落下中のミノを出現位置からやり直す.
+    pub fn clear_stack(&mut self) {
+        self.board.clear_stack();
+        self.board.spawn(self.board.dropping);
+        self.last_dropped = self.elapsed;
     }
 
     pub fn remove_lines(&mut self) {
@@ -197,12 +345,19 @@ impl Game {
     }
 
     pub fn put_and_spawn(&mut self) -> bool {
-        self.board.determine_dropping_mino();
+        self.board.determine_dropping_mino(self.elapsed);
 
         self.did_already_hold = false;
-        let mino = self.bag.pop();
+        let mino = match self.bag.pop() {
+            Some(mino) => mino,
+            None => {
+                self.events.push(GameEvent::RanOut);
+                return false;
+            }
+        };
 
-        self.last_dropped = self.elapsed;
+        self.entered_at = self.elapsed + self.entry_delay;
+        self.last_dropped = self.entered_at;
 
         if 0 < self.board.filled_lines().len() {
             self.combo += 1;
@@ -210,10 +365,33 @@ impl Game {
             self.combo = COMBO_INITIAL;
         }
 
-        self.board.spawn(mino)
+        let spawned = self.board.spawn(mino);
+        if !spawned {
+            self.events.push(GameEvent::ToppedOut);
+        }
+
+        spawned
     }
 }
 
+/// # ゲームイベント
+///
+/// 統計や演出など, ゲームの外側から進行を観測するための出来事.
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    Held {
+        mino: Tetrimino,
+    },
+    Put {
+        mino: Tetrimino,
+        removed_lines: usize,
+        reward: Option<ScoringReward>,
+    },
+    ToppedOut,
+    /// 決まった順番のミノを使い切り, 次のミノが出現しなかった.
+    RanOut,
+}
+
 #[derive(new)]
 pub enum DroppedOrNothing {
     Dropped(PutOrJustDropped),
@@ -258,3 +436,71 @@ impl Into<Point> for (f32, f32) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::tetris::model::mino_entity::MinoEntity;
+
+    /// 落下中の I ミノがちょうど埋める穴を残して最下段を埋め, `leftover` 段上にブロックを1つ置く.
+    fn game_completing_bottom_line(leftover: Option<usize>) -> Game {
+        let mut game = Game::with_queue(Rule::guideline(), vec![Tetrimino::I]);
+
+        let mut landed = game.board;
+        landed.hard_drop();
+        let holes = landed.dropping_mino_points();
+
+        let bottom = FIELD_UNIT_HEIGHT - 1;
+        for x in 0..FIELD_UNIT_WIDTH {
+            if holes.iter().all(|p| p.x as usize != x) {
+                game.board.confirmed_field[bottom][x] = MinoEntity::GARBAGE;
+            }
+        }
+        if let Some(height) = leftover {
+            game.board.confirmed_field[bottom - height][0] = MinoEntity::GARBAGE;
+        }
+
+        game
+    }
+
+    #[test_case(None => ScoringAction::PerfectClear)]
+    #[test_case(Some(2) => ScoringAction::Single)]
+    fn test_judges_perfect_clear_with_remaining_blocks(leftover: Option<usize>) -> ScoringAction {
+        let mut game = game_completing_bottom_line(leftover);
+        game.hard_drop();
+
+        game.take_events()
+            .into_iter()
+            .find_map(|event| match event {
+                GameEvent::Put { reward, .. } => reward.map(|r| r.action),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test_case(Rule::guideline() => 1)]
+    #[test_case(Rule::classic() => 1)]
+    fn test_soft_drop_score(rule: Rule) -> usize {
+        let mut game = Game::with_rule(rule);
+        game.soft_drop();
+
+        game.score
+    }
+
+    #[test]
+    fn test_refuses_hold_without_swapping() {
+        let mut game = Game::with_sequence(Rule::guideline(), vec![Tetrimino::T, Tetrimino::O]);
+
+        assert!(game.try_swap_hold());
+        assert!(!game.try_swap_hold());
+        assert_eq!(game.hold_mino, Some(Tetrimino::T));
+
+        // 使い切ったので, 空のホールドには入れられない
+        let mut game = Game::with_sequence(Rule::guideline(), vec![Tetrimino::T]);
+        assert!(!game.try_swap_hold());
+        assert!(game.hold_mino.is_none());
+        assert!(game.take_events().is_empty());
+    }
+}
@@ -1,38 +1,91 @@
 use std::collections::VecDeque;
 
-use rand::prelude::SliceRandom;
+use rand::{prelude::SliceRandom, rngs::StdRng, Rng, SeedableRng};
 
-use crate::tetris::model::tetrimino::Tetrimino;
+use crate::tetris::{model::tetrimino::Tetrimino, rule::Randomizer};
 
 /// # ミノを保有するバッグ
 ///
-/// ゲームに出現させるテトリミノを, 七種一巡の法則に従って供給する.
+/// ゲームに出現させるテトリミノを, `Randomizer` の決まりに従って供給する.
 pub struct MinoBag {
     queue: VecDeque<Tetrimino>,
+    rng: StdRng,
+    randomizer: Randomizer,
+    /// 決まった順番のミノだけを供給し, 補充しない.
+    fixed: bool,
 }
 
 impl MinoBag {
     pub fn new() -> MinoBag {
-        let mut queue = MinoBag::gen_shuffled_all_minos();
-        let mut added = MinoBag::gen_shuffled_all_minos();
-        queue.append(&mut added);
+        MinoBag::with_randomizer(Randomizer::SevenBag)
+    }
+
+    /// 同じシードからは同じ順番でミノが供給される.
+    pub fn with_seed(seed: u64) -> MinoBag {
+        MinoBag::with_rng(StdRng::seed_from_u64(seed), Randomizer::SevenBag)
+    }
+
+    pub fn with_seeded_randomizer(seed: u64, randomizer: Randomizer) -> MinoBag {
+        MinoBag::with_rng(StdRng::seed_from_u64(seed), randomizer)
+    }
+
+    pub fn with_randomizer(randomizer: Randomizer) -> MinoBag {
+        MinoBag::with_rng(StdRng::from_entropy(), randomizer)
+    }
 
+    /// `sequence` の順番でだけミノを供給し, 使い切ると空になる.
+    pub fn with_sequence(sequence: Vec<Tetrimino>) -> MinoBag {
         MinoBag {
-            queue: queue.into(),
+            queue: sequence.into(),
+            rng: StdRng::from_entropy(),
+            randomizer: Randomizer::SevenBag,
+            fixed: true,
         }
     }
 
-    pub fn pop(&mut self) -> Tetrimino {
-        let p = self.queue.pop_front().unwrap();
+    /// `queue` の順番でミノを供給した後, 7種1巡のミノを補充し続ける.
+    pub fn with_queue(queue: Vec<Tetrimino>) -> MinoBag {
+        let mut bag = MinoBag {
+            queue: queue.into(),
+            rng: StdRng::from_entropy(),
+            randomizer: Randomizer::SevenBag,
+            fixed: false,
+        };
+        bag.refill();
+
+        bag
+    }
 
-        if self.queue.len() < Tetrimino::all().len() {
-            let added = MinoBag::gen_shuffled_all_minos();
-            self.queue.append(&mut added.into());
-        }
+    fn with_rng(rng: StdRng, randomizer: Randomizer) -> MinoBag {
+        let mut bag = MinoBag {
+            queue: VecDeque::new(),
+            rng,
+            randomizer,
+            fixed: false,
+        };
+        bag.refill();
+
+        bag
+    }
+
+    /// 決まった順番のミノを使い切ったときは `None` を返す.
+    pub fn pop(&mut self) -> Option<Tetrimino> {
+        let p = self.queue.pop_front();
+        self.refill();
 
         p
     }
 
+    /// これから供給するミノを `queue` の順番に入れ替える. 足りない分はこのバッグの決まりで補充する.
+    pub fn replace_queue(&mut self, queue: Vec<Tetrimino>) {
+        self.queue = queue.into();
+        self.refill();
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.queue.len()
+    }
+
     /// ピークする個数はテトリミノ一巡分を超過してはならない.
     pub fn peek(&self, amount: usize) -> Vec<Tetrimino> {
         if amount > Tetrimino::all().len() {
@@ -41,17 +94,97 @@ impl MinoBag {
             );
         }
 
-        (0..amount)
-            .map(|idx| self.queue.get(idx).unwrap().to_owned())
-            .collect::<Vec<_>>()
+        self.queue.iter().take(amount).copied().collect::<Vec<_>>()
     }
 
-    fn gen_shuffled_all_minos() -> Vec<Tetrimino> {
-        let mut rng = rand::thread_rng();
+    /// テトリミノ一巡分をピークできるように補充する.
+    fn refill(&mut self) {
+        if self.fixed {
+            return;
+        }
+
+        while self.queue.len() < Tetrimino::all().len() {
+            let added = match self.randomizer {
+                Randomizer::SevenBag => self.gen_shuffled_all_minos(),
+                Randomizer::Classic => vec![self.gen_classic_mino()],
+            };
+            self.queue.extend(added);
+        }
+    }
 
+    fn gen_shuffled_all_minos(&mut self) -> Vec<Tetrimino> {
         let mut s = Tetrimino::all();
-        s.shuffle(&mut rng);
+        s.shuffle(&mut self.rng);
 
         s
     }
+
+    /// 8面のサイコロを振り, 8か直前と同じミノが出たときだけ7面のサイコロで振り直す.
+    fn gen_classic_mino(&mut self) -> Tetrimino {
+        let all = Tetrimino::all();
+        let previous = self.queue.back().copied();
+
+        match all.get(self.rng.gen_range(0..=all.len())) {
+            Some(mino) if Some(*mino) != previous => *mino,
+            _ => all[self.rng.gen_range(0..all.len())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_supplies_same_sequence() {
+        let mut left = MinoBag::with_seed(42);
+        let mut right = MinoBag::with_seed(42);
+
+        let left = (0..50).map(|_| left.pop().unwrap()).collect::<Vec<_>>();
+        let right = (0..50).map(|_| right.pop().unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn test_fixed_sequence_runs_out() {
+        let mut bag = MinoBag::with_sequence(vec![Tetrimino::T, Tetrimino::I]);
+
+        assert_eq!(bag.peek(5), vec![Tetrimino::T, Tetrimino::I]);
+        assert_eq!(bag.pop(), Some(Tetrimino::T));
+        assert_eq!(bag.pop(), Some(Tetrimino::I));
+        assert_eq!(bag.pop(), None);
+    }
+
+    #[test]
+    fn test_classic_randomizer_is_not_bag() {
+        let mut bag = MinoBag::with_rng(StdRng::seed_from_u64(3), Randomizer::Classic);
+
+        let minos = (0..70).map(|_| bag.pop().unwrap()).collect::<Vec<_>>();
+        let every_bag_complete = minos.chunks(7).all(|chunk| {
+            let mut chunk = chunk.to_vec();
+            chunk.sort();
+            chunk.dedup();
+            chunk.len() == Tetrimino::all().len()
+        });
+
+        assert!(!every_bag_complete);
+    }
+
+    #[test]
+    fn test_supplies_every_mino_once_per_bag() {
+        let mut bag = MinoBag::with_seed(7);
+
+        for _ in 0..3 {
+            let mut minos = (0..Tetrimino::all().len())
+                .map(|_| bag.pop().unwrap())
+                .collect::<Vec<_>>();
+            minos.sort();
+
+            let mut all = Tetrimino::all();
+            all.sort();
+
+            assert_eq!(minos, all);
+        }
+    }
 }
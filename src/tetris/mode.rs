@@ -0,0 +1,353 @@
+use std::time::Duration;
+
+use crate::{
+    model::{date::Date, play_record::Ranking},
+    tetris::{
+        game::{Game, GameEvent},
+        mode::{
+            classic::Classic,
+            dig::Dig,
+            forty_line::FortyLine,
+            four_wide::{FourWide, Residue},
+            marathon::Marathon,
+            master::Master,
+            mission::Mission,
+            opener::OpenerTrainer,
+            survival::Survival,
+            ultra::Ultra,
+            zen::Zen,
+        },
+        opener::{Opener, Placement},
+        rule::Rule,
+    },
+};
+
+pub mod classic;
+pub mod dig;
+pub mod forty_line;
+pub mod four_wide;
+pub mod marathon;
+pub mod master;
+pub mod mission;
+pub mod opener;
+pub mod puzzle;
+pub mod survival;
+pub mod ultra;
+pub mod zen;
+
+/// ルールだけを変えたモードで, マラソンとして目指すライン数.
+const MODIFIED_GOAL_LINES: usize = 100;
+
+/// # ゲームモード
+///
+/// ゲームの始め方と終わり方, 進行に合わせた難易度の変化を決める.
+pub trait GameMode {
+    /// 始める前に, 盤面や重力を整える.
+    fn setup(&mut self, _game: &mut Game) {}
+
+    /// 操作を反映した後に毎フレーム呼ばれ, 起きたイベントから進行を判定する.
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress;
+
+    /// 画面の右側に並べる, 項目名と値の組.
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)>;
+
+    /// 盤面の横に大きく表示する言葉. コンボなど, 遊びながら目に入ってほしいものに使う.
+    fn headline(&self, _game: &Game) -> Option<String> {
+        None
+    }
+
+    /// 盤面に枠だけで示す, 次にミノを置くべき場所.
+    fn guide(&self, _game: &Game) -> Option<Placement> {
+        None
+    }
+
+    /// 終わったときに大きく表示する言葉.
+    fn result_title(&self, cleared: bool) -> &'static str {
+        if cleared {
+            "CLEAR"
+        } else {
+            "GAME OVER"
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ModeProgress {
+    Playing,
+    Cleared,
+    Failed,
+}
+
+/// # モードの種類
+///
+/// 選択画面に並べたり, 記録を分けたりするために, モードとその設定を値として持つ.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ModeKind {
+    /// 40ラインを消すまでの時間を競う.
+    FortyLine,
+    /// `goal_lines` が `None` のときは, 積み上がるまで終わらない.
+    Marathon {
+        goal_lines: Option<usize>,
+    },
+    Ultra {
+        limit_secs: u64,
+    },
+    /// `messiness` は百分率で表した, 行ごとに穴の位置が変わる確率.
+    Dig {
+        rows: usize,
+        visible_rows: usize,
+        messiness: u8,
+    },
+    /// `interval_secs` は最初のおじゃまラインがせり上がるまでの秒数.
+    Survival {
+        interval_secs: u64,
+    },
+    Master,
+    /// ファミコン版のルールで, `start_level` から始める.
+    Classic {
+        start_level: usize,
+    },
+    /// 積み上がっても終わらず, 中断しても次に続きから遊べる.
+    Zen,
+    /// 置かれたブロックが `fade_secs` 秒で消えていく. 0のときは置いた瞬間に見えなくなる.
+    Invisible {
+        fade_secs: u64,
+    },
+    /// ミノの1マスが2x2マスの大きさになる.
+    Big,
+    /// 両側を埋めた4列の溝で, 底に `residue` の形のブロックを残してコンボを続ける.
+    FourWide {
+        residue: Residue,
+    },
+    /// `opener` の形を組めるかを練習する.
+    Opener {
+        opener: Opener,
+    },
+    /// `date` の日のデイリーチャレンジ. 同じ日には誰でも同じ順番でミノが出現する.
+    Daily {
+        date: Date,
+    },
+    /// 制限時間のある目標を順に達成していく.
+    Mission,
+}
+
+impl ModeKind {
+    /// 選択画面に並べる, 設定済みのモード.
+    pub fn presets() -> Vec<ModeKind> {
+        vec![
+            ModeKind::FortyLine,
+            ModeKind::Marathon {
+                goal_lines: Some(150),
+            },
+            ModeKind::Marathon { goal_lines: None },
+            ModeKind::Ultra { limit_secs: 120 },
+            ModeKind::Ultra { limit_secs: 180 },
+            ModeKind::Dig {
+                rows: 10,
+                visible_rows: 10,
+                messiness: 100,
+            },
+            ModeKind::Dig {
+                rows: 18,
+                visible_rows: 10,
+                messiness: 100,
+            },
+            ModeKind::Dig {
+                rows: 100,
+                visible_rows: 10,
+                messiness: 50,
+            },
+            ModeKind::Survival { interval_secs: 10 },
+            ModeKind::Survival { interval_secs: 5 },
+            ModeKind::Master,
+            ModeKind::Classic { start_level: 0 },
+            ModeKind::Classic { start_level: 9 },
+            ModeKind::Classic { start_level: 18 },
+            ModeKind::Zen,
+            ModeKind::Invisible { fade_secs: 5 },
+            ModeKind::Invisible { fade_secs: 0 },
+            ModeKind::Big,
+            ModeKind::Mission,
+        ]
+        .into_iter()
+        .chain(
+            Residue::all()
+                .into_iter()
+                .map(|residue| ModeKind::FourWide { residue }),
+        )
+        .chain(
+            Opener::all()
+                .into_iter()
+                .map(|opener| ModeKind::Opener { opener }),
+        )
+        .collect()
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            ModeKind::Marathon {
+                goal_lines: Some(lines),
+            } => format!("Marathon {}", lines),
+            ModeKind::Marathon { goal_lines: None } => "Marathon Endless".to_owned(),
+            ModeKind::Ultra { limit_secs } => format!("Ultra {} min", limit_secs / 60),
+            ModeKind::Dig {
+                rows, messiness, ..
+            } => format!("Dig {} (messiness {}%)", rows, messiness),
+            ModeKind::Survival { interval_secs } => format!("Survival {}s", interval_secs),
+            ModeKind::Master => "Master".to_owned(),
+            ModeKind::Classic { start_level } => format!("Classic Lv{}", start_level),
+            ModeKind::Zen => "Zen".to_owned(),
+            ModeKind::Invisible { fade_secs: 0 } => "Invisible".to_owned(),
+            ModeKind::Invisible { fade_secs } => format!("Fading {}s", fade_secs),
+            ModeKind::Big => "Big".to_owned(),
+            ModeKind::FourWide { residue } => format!("4-Wide ({} residue)", residue.name()),
+            ModeKind::Opener { opener } => format!("Opener: {}", opener.name()),
+            ModeKind::Daily { date } => format!("Daily {}", date),
+            ModeKind::Mission => "Mission".to_owned(),
+            ModeKind::FortyLine => "40 Line".to_owned(),
+        }
+    }
+
+    /// 記録を保存するときのモード名.
+    pub fn record_name(&self) -> String {
+        match self {
+            ModeKind::Marathon {
+                goal_lines: Some(lines),
+            } => format!("marathon{}", lines),
+            ModeKind::Marathon { goal_lines: None } => "marathon-endless".to_owned(),
+            ModeKind::Ultra { limit_secs } => format!("ultra{}", limit_secs),
+            ModeKind::Dig {
+                rows,
+                visible_rows,
+                messiness,
+            } => format!("dig{}-{}-{}", rows, visible_rows, messiness),
+            ModeKind::Survival { interval_secs } => format!("survival{}", interval_secs),
+            ModeKind::Master => "master".to_owned(),
+            ModeKind::Classic { start_level } => format!("classic{}", start_level),
+            ModeKind::Zen => "zen".to_owned(),
+            ModeKind::Invisible { fade_secs } => format!("invisible{}", fade_secs),
+            ModeKind::Big => "big".to_owned(),
+            ModeKind::FourWide { residue } => format!("4wide-{}", residue.name()),
+            ModeKind::Opener { opener } => {
+                format!("opener-{}", opener.name().to_lowercase().replace(' ', "-"))
+            }
+            ModeKind::Daily { date } => format!("daily-{}", date),
+            ModeKind::Mission => "mission".to_owned(),
+            ModeKind::FortyLine => "40line".to_owned(),
+        }
+    }
+
+    pub fn ranking(&self) -> Ranking {
+        match self {
+            ModeKind::Marathon { .. }
+            | ModeKind::Ultra { .. }
+            | ModeKind::Master
+            | ModeKind::Classic { .. }
+            | ModeKind::Zen
+            | ModeKind::Invisible { .. }
+            | ModeKind::Big
+            | ModeKind::FourWide { .. } => Ranking::HigherScore,
+            ModeKind::Dig { .. }
+            | ModeKind::Opener { .. }
+            | ModeKind::Daily { .. }
+            | ModeKind::Mission
+            | ModeKind::FortyLine => Ranking::ShorterTime,
+            ModeKind::Survival { .. } => Ranking::LongerTime,
+        }
+    }
+
+    /// `seed` はミノの出現順を決めたシードで, モードが使う乱数もここから決める.
+    pub fn create(&self, seed: u64) -> Box<dyn GameMode> {
+        match *self {
+            ModeKind::Marathon { goal_lines } => Box::new(Marathon::new(goal_lines)),
+            ModeKind::Ultra { limit_secs } => Box::new(Ultra::new(Duration::from_secs(limit_secs))),
+            ModeKind::Dig {
+                rows,
+                visible_rows,
+                messiness,
+            } => Box::new(Dig::new(rows, visible_rows, messiness, seed)),
+            ModeKind::Survival { interval_secs } => {
+                Box::new(Survival::new(Duration::from_secs(interval_secs), seed))
+            }
+            ModeKind::Master => Box::new(Master::new()),
+            ModeKind::Classic { start_level } => Box::new(Classic::new(start_level)),
+            ModeKind::Zen => Box::new(Zen),
+            ModeKind::FourWide { residue } => Box::new(FourWide::new(residue)),
+            ModeKind::Opener { opener } => Box::new(OpenerTrainer::new(opener)),
+            ModeKind::FortyLine | ModeKind::Daily { .. } => Box::new(FortyLine::new()),
+            ModeKind::Mission => Box::new(Mission::new()),
+            ModeKind::Invisible { .. } | ModeKind::Big => {
+                Box::new(Marathon::new(Some(MODIFIED_GOAL_LINES)))
+            }
+        }
+    }
+
+    /// 決まった順番でミノを出現させるときのシード.
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            ModeKind::Daily { date } => Some(date.seed()),
+            _ => None,
+        }
+    }
+
+    /// 中断したプレイを保存し, 次に選んだときに続きから始めるかどうか.
+    pub fn is_resumable(&self) -> bool {
+        matches!(self, ModeKind::Zen)
+    }
+
+    pub fn rule(&self) -> Rule {
+        match self {
+            ModeKind::Classic { .. } => Rule::classic(),
+            ModeKind::Invisible { fade_secs } => Rule {
+                fade: Some(Duration::from_secs(*fade_secs)),
+                ..Rule::guideline()
+            },
+            ModeKind::Big => Rule {
+                big: true,
+                ..Rule::guideline()
+            },
+            _ => Rule::guideline(),
+        }
+    }
+}
+
+pub fn topped_out(events: &[GameEvent]) -> bool {
+    events
+        .iter()
+        .any(|event| matches!(event, GameEvent::ToppedOut))
+}
+
+/// `分:秒:1/100秒` の形で経過時間を表す.
+pub fn format_time(elapsed: &Duration) -> String {
+    let min = elapsed.as_secs() / 60;
+    let sec = elapsed.as_secs() % 60;
+    let centi_sec = elapsed.subsec_millis() / 10;
+
+    format!("{:>03}:{:>02}:{:>02}", min, sec, centi_sec)
+}
+
+/// `分:秒.1/1000秒` の形で, 結果として経過時間を細かく表す.
+pub fn format_precise_time(elapsed: &Duration) -> String {
+    let min = elapsed.as_secs() / 60;
+    let sec = elapsed.as_secs() % 60;
+
+    format!("{:>02}:{:>02}.{:>03}", min, sec, elapsed.subsec_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(&Duration::from_millis(83_456)), "001:23:45");
+    }
+
+    #[test]
+    fn test_format_precise_time() {
+        assert_eq!(
+            format_precise_time(&Duration::from_millis(83_456)),
+            "01:23.456"
+        );
+    }
+}
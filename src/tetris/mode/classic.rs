@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use crate::tetris::{
+    game::{Game, GameEvent},
+    mode::{format_time, topped_out, GameMode, ModeProgress},
+    rule::FRAME,
+};
+
+/// ミノを置いてから次のミノが出るまでのフレーム数.
+const ENTRY_DELAY_FRAMES: u32 = 10;
+
+/// レベルごとの, 1マス落ちるまでのフレーム数. 29以降は全て1になる.
+const GRAVITY_FRAMES: [u32; 29] = [
+    48, 43, 38, 33, 28, 23, 18, 13, 8, 6, 5, 5, 5, 4, 4, 4, 3, 3, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+];
+
+/// # クラシック
+///
+/// ファミコン版のルールで, 積み上がるまでレベルを上げ続ける.
+pub struct Classic {
+    start_level: usize,
+    level: usize,
+}
+
+impl Classic {
+    pub fn new(start_level: usize) -> Classic {
+        Classic {
+            start_level,
+            level: start_level,
+        }
+    }
+
+    fn apply_level(&self, game: &mut Game) {
+        let interval = classic_drop_interval(self.level);
+
+        game.drop_interval = interval;
+        // 接地したら次の落下のタイミングで置かれる
+        game.lock_delay = interval;
+        game.entry_delay = FRAME * ENTRY_DELAY_FRAMES;
+        game.score_multiplier = self.level + 1;
+    }
+}
+
+impl GameMode for Classic {
+    fn setup(&mut self, game: &mut Game) {
+        self.apply_level(game);
+    }
+
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        let level = classic_level(self.start_level, game.removed_line_count);
+        if level != self.level {
+            self.level = level;
+            self.apply_level(game);
+        }
+
+        if topped_out(events) {
+            ModeProgress::Failed
+        } else {
+            ModeProgress::Playing
+        }
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("LEVEL", self.level.to_string()),
+            ("LINES", game.removed_line_count.to_string()),
+            ("SCORE", game.score.to_string()),
+            ("TIME", format_time(&game.elapsed)),
+        ]
+    }
+}
+
+/// 1マス落ちるまでの時間.
+pub fn classic_drop_interval(level: usize) -> Duration {
+    let frames = GRAVITY_FRAMES.get(level).copied().unwrap_or(1);
+
+    FRAME * frames
+}
+
+/// 開始レベルによっては, 最初のレベルアップまでに10ラインより多く消す必要がある.
+pub fn classic_level(start_level: usize, lines: usize) -> usize {
+    let first_transition =
+        (start_level * 10 + 10).min((start_level * 10).saturating_sub(50).max(100));
+
+    if lines < first_transition {
+        start_level
+    } else {
+        start_level + 1 + (lines - first_transition) / 10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(0, 9, 0)]
+    #[test_case(0, 10, 1)]
+    #[test_case(0, 35, 3)]
+    #[test_case(9, 99, 9)]
+    #[test_case(9, 100, 10)]
+    #[test_case(18, 129, 18)]
+    #[test_case(18, 130, 19)]
+    #[test_case(18, 140, 20)]
+    fn test_classic_level(start_level: usize, lines: usize, expected: usize) {
+        assert_eq!(classic_level(start_level, lines), expected);
+    }
+
+    #[test_case(0, FRAME * 48)]
+    #[test_case(19, FRAME * 2)]
+    #[test_case(29, FRAME)]
+    fn test_classic_drop_interval(level: usize, expected: Duration) {
+        assert_eq!(classic_drop_interval(level), expected);
+    }
+}
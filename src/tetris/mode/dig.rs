@@ -0,0 +1,173 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::tetris::{
+    board::{Board, FIELD_UNIT_WIDTH},
+    game::{Game, GameEvent},
+    mode::{format_time, topped_out, GameMode, ModeProgress},
+    model::mino_entity::MinoEntity,
+};
+
+/// ミノの順番と同じシードから, 別の乱数列を作るために混ぜる値.
+const SEED_SALT: u64 = 0x6469_6700;
+
+/// # ディグ
+///
+/// 穴の空いたおじゃまラインを下から掘り進め, 全て消すまでの時間を競う.
+pub struct Dig {
+    /// まだせり上がっていないおじゃまラインの数.
+    unraised_rows: usize,
+    /// 一度に盤面に出しておくおじゃまラインの数.
+    visible_rows: usize,
+    /// 百分率で表した, 1つ上の行と穴の位置が変わる確率.
+    messiness: u8,
+    hole_x: usize,
+    rng: StdRng,
+}
+
+impl Dig {
+    /// 同じシードからは, 同じ位置に穴の空いたおじゃまラインがせり上がる.
+    pub fn new(rows: usize, visible_rows: usize, messiness: u8, seed: u64) -> Dig {
+        let mut rng = StdRng::seed_from_u64(seed ^ SEED_SALT);
+        let hole_x = rng.gen_range(0..FIELD_UNIT_WIDTH);
+
+        Dig {
+            unraised_rows: rows,
+            visible_rows,
+            messiness,
+            hole_x,
+            rng,
+        }
+    }
+
+    /// 盤面にあるものとまだせり上がっていないものを合わせた, 残りのおじゃまラインの数.
+    pub fn remaining_rows(&self, board: &Board) -> usize {
+        self.unraised_rows + garbage_rows(board)
+    }
+
+    /// 盤面のおじゃまラインが `visible_rows` になるまでせり上げる.
+    fn fill(&mut self, game: &mut Game) {
+        while self.unraised_rows > 0 && garbage_rows(&game.board) < self.visible_rows {
+            if self.rng.gen_range(0..100) < self.messiness {
+                let shift = self.rng.gen_range(1..FIELD_UNIT_WIDTH);
+                self.hole_x = (self.hole_x + shift) % FIELD_UNIT_WIDTH;
+            }
+
+            self.unraised_rows -= 1;
+            if !game.raise_garbage(1, self.hole_x) {
+                return;
+            }
+        }
+    }
+}
+
+impl GameMode for Dig {
+    fn setup(&mut self, game: &mut Game) {
+        self.fill(game);
+    }
+
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        if topped_out(events) {
+            return ModeProgress::Failed;
+        }
+
+        if events
+            .iter()
+            .any(|event| matches!(event, GameEvent::Put { .. }))
+        {
+            self.fill(game);
+        }
+
+        if self.remaining_rows(&game.board) == 0 {
+            ModeProgress::Cleared
+        } else {
+            ModeProgress::Playing
+        }
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("GARBAGE", self.remaining_rows(&game.board).to_string()),
+            ("LINES", game.removed_line_count.to_string()),
+            ("TIME", format_time(&game.elapsed)),
+        ]
+    }
+}
+
+fn garbage_rows(board: &Board) -> usize {
+    board
+        .confirmed_field
+        .iter()
+        .filter(|line| line.contains(&MinoEntity::GARBAGE))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tetris::board::FIELD_UNIT_HEIGHT;
+
+    #[test]
+    fn test_keeps_visible_rows_filled() {
+        let mut game = Game::with_seed(0);
+        let mut dig = Dig::new(18, 10, 100, 0);
+        dig.setup(&mut game);
+
+        assert_eq!(garbage_rows(&game.board), 10);
+        assert_eq!(dig.remaining_rows(&game.board), 18);
+
+        // 一番下のおじゃまラインを消したことにする
+        game.board.confirmed_field[FIELD_UNIT_HEIGHT - 1] = [MinoEntity::AIR; FIELD_UNIT_WIDTH];
+        let put = GameEvent::Put {
+            mino: game.board.dropping,
+            removed_lines: 1,
+            reward: None,
+        };
+        assert_eq!(dig.update(&mut game, &[put]), ModeProgress::Playing);
+
+        assert_eq!(garbage_rows(&game.board), 10);
+        assert_eq!(dig.remaining_rows(&game.board), 17);
+    }
+
+    #[test]
+    fn test_every_row_has_single_hole() {
+        let mut game = Game::with_seed(0);
+        let mut dig = Dig::new(5, 5, 100, 0);
+        dig.setup(&mut game);
+
+        let rows = &game.board.confirmed_field[FIELD_UNIT_HEIGHT - 5..];
+        for (row, next) in rows.iter().zip(rows.iter().skip(1)) {
+            assert_eq!(row.iter().filter(|e| e.is_air()).count(), 1);
+
+            let hole = row.iter().position(|e| e.is_air());
+            let next_hole = next.iter().position(|e| e.is_air());
+            assert_ne!(hole, next_hole);
+        }
+    }
+
+    #[test]
+    fn test_same_seed_makes_same_garbage() {
+        let mut game1 = Game::with_seed(0);
+        Dig::new(5, 5, 100, 42).setup(&mut game1);
+        let mut game2 = Game::with_seed(0);
+        Dig::new(5, 5, 100, 42).setup(&mut game2);
+
+        let holes = |game: &Game| -> Vec<_> {
+            game.board.confirmed_field[FIELD_UNIT_HEIGHT - 5..]
+                .iter()
+                .map(|row| row.iter().position(|e| e.is_air()))
+                .collect()
+        };
+        assert_eq!(holes(&game1), holes(&game2));
+    }
+
+    #[test]
+    fn test_clears_when_all_garbage_is_dug() {
+        let mut game = Game::with_seed(0);
+        let mut dig = Dig::new(1, 1, 0, 0);
+        dig.setup(&mut game);
+
+        game.board.confirmed_field[FIELD_UNIT_HEIGHT - 1] = [MinoEntity::AIR; FIELD_UNIT_WIDTH];
+
+        assert_eq!(dig.update(&mut game, &[]), ModeProgress::Cleared);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::tetris::{
+    game::{Game, GameEvent},
+    mode::{format_time, topped_out, GameMode, ModeProgress},
+};
+
+/// 40ラインで消すライン数.
+pub const FORTY_LINE_GOAL: usize = 40;
+
+/// # 40ライン
+///
+/// 決まったライン数を消すまでの時間を競う. 重力は最後まで変わらない.
+pub struct FortyLine {
+    goal_lines: usize,
+}
+
+impl FortyLine {
+    pub fn new() -> FortyLine {
+        FortyLine {
+            goal_lines: FORTY_LINE_GOAL,
+        }
+    }
+
+    pub fn remaining_lines(&self, game: &Game) -> usize {
+        self.goal_lines.saturating_sub(game.removed_line_count)
+    }
+}
+
+impl GameMode for FortyLine {
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        match () {
+            _ if topped_out(events) => ModeProgress::Failed,
+            _ if self.remaining_lines(game) == 0 => ModeProgress::Cleared,
+            _ => ModeProgress::Playing,
+        }
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("REMAIN", self.remaining_lines(game).to_string()),
+            ("TIME", format_time(&game.elapsed)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clears_at_goal_lines() {
+        let mut game = Game::with_seed(0);
+        let mut forty_line = FortyLine::new();
+
+        game.removed_line_count = 39;
+        assert_eq!(forty_line.update(&mut game, &[]), ModeProgress::Playing);
+        assert_eq!(forty_line.remaining_lines(&game), 1);
+
+        game.removed_line_count = 41;
+        assert_eq!(forty_line.update(&mut game, &[]), ModeProgress::Cleared);
+        assert_eq!(forty_line.remaining_lines(&game), 0);
+    }
+}
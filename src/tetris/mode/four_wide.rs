@@ -0,0 +1,213 @@
+use crate::tetris::{
+    board::FIELD_UNIT_HEIGHT,
+    game::{Game, GameEvent},
+    mode::{topped_out, GameMode, ModeProgress},
+    model::mino_entity::MinoEntity,
+};
+
+/// 4列の溝の左端の列.
+const WELL_LEFT: usize = 3;
+const WELL_WIDTH: usize = 4;
+
+/// # 4列の溝に残すブロックの形
+///
+/// 溝に3マスのブロックを残しておくと, ミノを置くたびに1ラインずつ消し続けられる.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Residue {
+    Left,
+    Right,
+    Flat,
+}
+
+impl Residue {
+    pub fn all() -> Vec<Residue> {
+        vec![Residue::Left, Residue::Right, Residue::Flat]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Residue::Left => "left",
+            Residue::Right => "right",
+            Residue::Flat => "flat",
+        }
+    }
+
+    /// 溝の底に詰める行. 上の行から順に, 'X' がブロックを表す.
+    fn rows(&self) -> &'static [&'static str] {
+        match self {
+            Residue::Left => &["X...", "XX.."],
+            Residue::Right => &["...X", "..XX"],
+            Residue::Flat => &["XXX."],
+        }
+    }
+}
+
+/// # 4列REN練習
+///
+/// 両側を埋めた4列の溝で, どこまでコンボを続けられるかを練習する.
+/// コンボが途切れたり積み上がったりすると, すぐに最初の盤面に戻る.
+pub struct FourWide {
+    residue: Residue,
+    longest_combo: usize,
+    attempts: usize,
+}
+
+impl FourWide {
+    pub fn new(residue: Residue) -> FourWide {
+        FourWide {
+            residue,
+            longest_combo: 0,
+            attempts: 0,
+        }
+    }
+
+    /// 盤面を片付け, 両側の壁と溝の底のブロックを置き直す.
+    fn reset(&mut self, game: &mut Game) {
+        self.attempts += 1;
+        game.clear_stack();
+
+        let rows = self.residue.rows();
+        for (idx, row) in rows.iter().enumerate() {
+            let y = FIELD_UNIT_HEIGHT - rows.len() + idx;
+
+            for (dx, cell) in row.chars().enumerate() {
+                if cell == 'X' {
+                    game.board.confirmed_field[y][WELL_LEFT + dx] = MinoEntity::GARBAGE;
+                }
+            }
+        }
+        build_walls(game);
+    }
+}
+
+impl GameMode for FourWide {
+    fn setup(&mut self, game: &mut Game) {
+        self.reset(game);
+    }
+
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        self.longest_combo = self.longest_combo.max(game.combo());
+
+        let broke = events.iter().any(|event| {
+            matches!(
+                event,
+                GameEvent::Put {
+                    removed_lines: 0,
+                    ..
+                }
+            )
+        });
+        if broke || topped_out(events) {
+            self.reset(game);
+        } else if events
+            .iter()
+            .any(|event| matches!(event, GameEvent::Put { .. }))
+        {
+            // 消えたラインの分だけ下がった壁を, 上から継ぎ足す
+            build_walls(game);
+        }
+
+        ModeProgress::Playing
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("COMBO", game.combo().to_string()),
+            ("LONGEST", self.longest_combo.to_string()),
+            ("TRIES", self.attempts.to_string()),
+        ]
+    }
+
+    fn headline(&self, game: &Game) -> Option<String> {
+        match game.combo() {
+            0 => None,
+            combo => Some(format!("{} COMBO", combo)),
+        }
+    }
+}
+
+fn build_walls(game: &mut Game) {
+    for line in game.board.confirmed_field.iter_mut() {
+        for (x, entity) in line.iter_mut().enumerate() {
+            if !(WELL_LEFT..WELL_LEFT + WELL_WIDTH).contains(&x) {
+                *entity = MinoEntity::GARBAGE;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::tetris::board::FIELD_UNIT_WIDTH;
+
+    fn well_blocks(game: &Game) -> usize {
+        game.board
+            .confirmed_field
+            .iter()
+            .map(|line| {
+                line[WELL_LEFT..WELL_LEFT + WELL_WIDTH]
+                    .iter()
+                    .filter(|entity| !entity.is_air())
+                    .count()
+            })
+            .sum()
+    }
+
+    fn put(removed_lines: usize) -> GameEvent {
+        GameEvent::Put {
+            mino: Game::with_seed(0).board.dropping,
+            removed_lines,
+            reward: None,
+        }
+    }
+
+    #[test_case(Residue::Left)]
+    #[test_case(Residue::Right)]
+    #[test_case(Residue::Flat)]
+    fn test_builds_walls_and_residue(residue: Residue) {
+        let mut game = Game::with_seed(0);
+        FourWide::new(residue).setup(&mut game);
+
+        assert_eq!(well_blocks(&game), 3);
+        assert!(game.board.confirmed_field.iter().all(|line| {
+            (0..FIELD_UNIT_WIDTH)
+                .filter(|x| !(WELL_LEFT..WELL_LEFT + WELL_WIDTH).contains(x))
+                .all(|x| line[x] == MinoEntity::GARBAGE)
+        }));
+    }
+
+    #[test]
+    fn test_resets_when_combo_breaks() {
+        let mut game = Game::with_seed(0);
+        let mut four_wide = FourWide::new(Residue::Left);
+        four_wide.setup(&mut game);
+        four_wide.longest_combo = 5;
+
+        game.board.confirmed_field[10][WELL_LEFT] = MinoEntity::GARBAGE;
+        assert_eq!(
+            four_wide.update(&mut game, &[put(0)]),
+            ModeProgress::Playing
+        );
+
+        assert_eq!(well_blocks(&game), 3);
+        assert_eq!(four_wide.longest_combo, 5);
+        assert_eq!(four_wide.attempts, 2);
+    }
+
+    #[test]
+    fn test_refills_walls_after_clearing() {
+        let mut game = Game::with_seed(0);
+        let mut four_wide = FourWide::new(Residue::Flat);
+        four_wide.setup(&mut game);
+
+        // 1ライン消えて, 一番上の行が空いたことにする
+        game.board.confirmed_field[0] = [MinoEntity::AIR; FIELD_UNIT_WIDTH];
+        four_wide.update(&mut game, &[put(1)]);
+
+        assert!(game.board.confirmed_field[0][0] == MinoEntity::GARBAGE);
+        assert_eq!(four_wide.attempts, 1);
+    }
+}
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use crate::tetris::{
+    game::{Game, GameEvent},
+    mode::{format_time, topped_out, GameMode, ModeProgress},
+};
+
+/// このライン数を消すごとにレベルが上がる.
+pub const LINES_PER_LEVEL: usize = 10;
+
+/// # マラソン
+///
+/// ラインを消すほどレベルが上がり, 落下が速く, 得点が高くなっていく.
+pub struct Marathon {
+    goal_lines: Option<usize>,
+    level: usize,
+}
+
+impl Marathon {
+    pub fn new(goal_lines: Option<usize>) -> Marathon {
+        Marathon {
+            goal_lines,
+            level: 1,
+        }
+    }
+
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    fn apply_level(&self, game: &mut Game) {
+        game.drop_interval = guideline_drop_interval(self.level);
+        game.score_multiplier = self.level;
+    }
+}
+
+impl GameMode for Marathon {
+    fn setup(&mut self, game: &mut Game) {
+        self.apply_level(game);
+    }
+
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        let level = 1 + game.removed_line_count / LINES_PER_LEVEL;
+        if level != self.level {
+            self.level = level;
+            self.apply_level(game);
+        }
+
+        match self.goal_lines {
+            _ if topped_out(events) => ModeProgress::Failed,
+            Some(goal) if goal <= game.removed_line_count => ModeProgress::Cleared,
+            _ => ModeProgress::Playing,
+        }
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        let lines = match self.goal_lines {
+            Some(goal) => format!("{}/{}", game.removed_line_count, goal),
+            None => game.removed_line_count.to_string(),
+        };
+
+        vec![
+            ("LEVEL", self.level().to_string()),
+            ("LINES", lines),
+            (
+                "NEXT LV",
+                (LINES_PER_LEVEL - game.removed_line_count % LINES_PER_LEVEL).to_string(),
+            ),
+            ("SCORE", game.score.to_string()),
+            ("TIME", format_time(&game.elapsed)),
+        ]
+    }
+}
+
+/// ガイドラインの重力. `(0.8 - (level - 1) * 0.007) ^ (level - 1)` 秒で1マス落ちる.
+pub fn guideline_drop_interval(level: usize) -> Duration {
+    let level = level.clamp(1, 20) as i32;
+    let secs = (0.8 - f64::from(level - 1) * 0.007).powi(level - 1);
+
+    Duration::from_secs_f64(secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_rises_every_ten_lines() {
+        let mut game = Game::with_seed(0);
+        let mut marathon = Marathon::new(Some(150));
+        marathon.setup(&mut game);
+        let first_interval = game.drop_interval;
+
+        game.removed_line_count = 25;
+        assert_eq!(marathon.update(&mut game, &[]), ModeProgress::Playing);
+
+        assert_eq!(marathon.level(), 3);
+        assert_eq!(game.score_multiplier, 3);
+        assert!(game.drop_interval < first_interval);
+    }
+
+    #[test]
+    fn test_ends_at_goal_or_top_out() {
+        let mut game = Game::with_seed(0);
+        let mut marathon = Marathon::new(Some(150));
+
+        game.removed_line_count = 150;
+        assert_eq!(marathon.update(&mut game, &[]), ModeProgress::Cleared);
+
+        let mut endless = Marathon::new(None);
+        assert_eq!(endless.update(&mut game, &[]), ModeProgress::Playing);
+        assert_eq!(
+            endless.update(&mut game, &[GameEvent::ToppedOut]),
+            ModeProgress::Failed
+        );
+    }
+
+    #[test]
+    fn test_gravity_gets_faster() {
+        assert_eq!(guideline_drop_interval(1), Duration::from_secs(1));
+
+        for level in 1..20 {
+            assert!(guideline_drop_interval(level + 1) < guideline_drop_interval(level));
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use std::time::Duration;
+
+use crate::tetris::{
+    game::{Game, GameEvent},
+    mode::{format_time, topped_out, GameMode, ModeProgress},
+    rule::FRAME,
+};
+
+pub const MAX_LEVEL: usize = 999;
+const LEVELS_PER_SECTION: usize = 100;
+
+/// このレベル以上で使う, 1フレームに落ちるマス数の256倍. 5120で20Gになる.
+const GRAVITY_TABLE: [(usize, u32); 30] = [
+    (0, 4),
+    (30, 6),
+    (35, 8),
+    (40, 10),
+    (50, 12),
+    (60, 16),
+    (70, 32),
+    (80, 48),
+    (90, 64),
+    (100, 80),
+    (120, 96),
+    (140, 112),
+    (160, 128),
+    (170, 144),
+    (200, 4),
+    (220, 32),
+    (230, 64),
+    (233, 96),
+    (236, 128),
+    (239, 160),
+    (243, 192),
+    (247, 224),
+    (251, 256),
+    (300, 512),
+    (330, 768),
+    (360, 1024),
+    (400, 1280),
+    (420, 1024),
+    (450, 768),
+    (500, 5120),
+];
+
+/// セクションごとの出現遅延と固定遅延のフレーム数.
+const DELAY_TABLE: [(u32, u32); 10] = [
+    (30, 30),
+    (27, 30),
+    (27, 30),
+    (25, 30),
+    (25, 30),
+    (25, 30),
+    (16, 30),
+    (12, 30),
+    (6, 30),
+    (6, 17),
+];
+
+/// 段位と, その段位になるのに必要な得点.
+const GRADES: [(&str, usize); 18] = [
+    ("9", 0),
+    ("8", 400),
+    ("7", 800),
+    ("6", 1400),
+    ("5", 2000),
+    ("4", 3500),
+    ("3", 5500),
+    ("2", 8000),
+    ("1", 12000),
+    ("S1", 16000),
+    ("S2", 22000),
+    ("S3", 30000),
+    ("S4", 40000),
+    ("S5", 52000),
+    ("S6", 66000),
+    ("S7", 82000),
+    ("S8", 100000),
+    ("S9", 120000),
+];
+const GRAND_MASTER: &str = "GM";
+
+/// グランドマスターになるために, チェックポイントで満たしていなければならない段位と時間.
+const GM_REQUIREMENTS: [(usize, &str, Duration); 3] = [
+    (300, "1", Duration::from_secs(4 * 60 + 15)),
+    (500, "S4", Duration::from_secs(7 * 60 + 30)),
+    (MAX_LEVEL, "S9", Duration::from_secs(13 * 60 + 30)),
+];
+
+/// # マスター
+///
+/// ミノを置くたびにレベルが上がり, 最後は20Gに達する. 遅延はセクションごとに縮み,
+/// チェックポイントでの得点と時間で段位が決まる.
+pub struct Master {
+    level: usize,
+    grade: &'static str,
+    /// ここまでのチェックポイントで, グランドマスターの条件を満たし続けているかどうか.
+    qualifies_gm: bool,
+}
+
+impl Master {
+    pub fn new() -> Master {
+        Master {
+            level: 0,
+            grade: GRADES[0].0,
+            qualifies_gm: true,
+        }
+    }
+
+    pub fn grade(&self) -> &'static str {
+        self.grade
+    }
+
+    fn section(&self) -> usize {
+        self.level.min(MAX_LEVEL) / LEVELS_PER_SECTION
+    }
+
+    fn apply_level(&self, game: &mut Game) {
+        let (are, lock) = DELAY_TABLE[self.section()];
+
+        game.drop_interval = gravity_interval(self.level);
+        game.entry_delay = FRAME * are;
+        game.lock_delay = FRAME * lock;
+    }
+
+    /// 次のセクションに進むか, 最後のレベルに達するまでは, ミノを置くだけではレベルが上がらない.
+    fn stops_at_section_end(&self) -> bool {
+        self.level % LEVELS_PER_SECTION == LEVELS_PER_SECTION - 1 || self.level == MAX_LEVEL - 1
+    }
+
+    fn pass_checkpoint(&mut self, game: &Game) {
+        self.grade = grade_for(game.score);
+
+        let requirement = GM_REQUIREMENTS
+            .iter()
+            .find(|(level, _, _)| *level == self.level.min(MAX_LEVEL));
+        if let Some((_, grade, time)) = requirement {
+            self.qualifies_gm &=
+                grade_rank(self.grade) >= grade_rank(grade) && game.elapsed <= *time;
+        }
+
+        if self.level >= MAX_LEVEL && self.qualifies_gm {
+            self.grade = GRAND_MASTER;
+        }
+    }
+}
+
+impl GameMode for Master {
+    fn setup(&mut self, game: &mut Game) {
+        self.apply_level(game);
+    }
+
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        for event in events {
+            if let GameEvent::Put { removed_lines, .. } = event {
+                let before = self.level;
+                if *removed_lines > 0 || !self.stops_at_section_end() {
+                    self.level = (self.level + 1 + removed_lines).min(MAX_LEVEL);
+                }
+
+                let passed_checkpoint = before / LEVELS_PER_SECTION
+                    != self.level / LEVELS_PER_SECTION
+                    || (before < MAX_LEVEL && self.level == MAX_LEVEL);
+                if passed_checkpoint {
+                    self.pass_checkpoint(game);
+                }
+            }
+        }
+        self.apply_level(game);
+
+        match () {
+            _ if topped_out(events) => ModeProgress::Failed,
+            _ if MAX_LEVEL <= self.level => ModeProgress::Cleared,
+            _ => ModeProgress::Playing,
+        }
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        let target = ((self.section() + 1) * LEVELS_PER_SECTION).min(MAX_LEVEL);
+
+        vec![
+            ("GRADE", self.grade().to_owned()),
+            ("LEVEL", format!("{}/{}", self.level, target)),
+            ("SCORE", game.score.to_string()),
+            ("TIME", format_time(&game.elapsed)),
+        ]
+    }
+
+    fn result_title(&self, _cleared: bool) -> &'static str {
+        self.grade()
+    }
+}
+
+/// レベルに応じた, 1マス落ちるまでの時間. 20Gに達したときは0になる.
+pub fn gravity_interval(level: usize) -> Duration {
+    let gravity = GRAVITY_TABLE
+        .iter()
+        .rev()
+        .find(|(from, _)| *from <= level)
+        .map(|(_, gravity)| *gravity)
+        .unwrap();
+
+    if gravity >= 5120 {
+        Duration::ZERO
+    } else {
+        FRAME * 256 / gravity
+    }
+}
+
+fn grade_for(score: usize) -> &'static str {
+    GRADES
+        .iter()
+        .rev()
+        .find(|(_, required)| *required <= score)
+        .map(|(grade, _)| *grade)
+        .unwrap()
+}
+
+fn grade_rank(grade: &str) -> usize {
+    GRADES
+        .iter()
+        .position(|(name, _)| *name == grade)
+        .unwrap_or(GRADES.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::tetris::model::tetrimino::Tetrimino;
+
+    fn put(removed_lines: usize) -> GameEvent {
+        GameEvent::Put {
+            mino: Tetrimino::T,
+            removed_lines,
+            reward: None,
+        }
+    }
+
+    #[test_case(0, FRAME * 64)]
+    #[test_case(251, FRAME)]
+    #[test_case(300, FRAME / 2)]
+    #[test_case(500, Duration::ZERO)]
+    fn test_gravity_interval(level: usize, expected: Duration) {
+        assert_eq!(gravity_interval(level), expected);
+    }
+
+    #[test]
+    fn test_stops_at_section_end_until_lines_are_removed() {
+        let mut game = Game::with_seed(0);
+        let mut master = Master::new();
+        master.level = 98;
+
+        master.update(&mut game, &[put(0)]);
+        assert_eq!(master.level, 99);
+        master.update(&mut game, &[put(0)]);
+        assert_eq!(master.level, 99);
+
+        master.update(&mut game, &[put(2)]);
+        assert_eq!(master.level, 102);
+        assert_eq!(game.entry_delay, FRAME * 27);
+    }
+
+    #[test]
+    fn test_grades_at_checkpoint() {
+        let mut game = Game::with_seed(0);
+        let mut master = Master::new();
+        master.level = 99;
+        game.score = 2500;
+
+        master.update(&mut game, &[put(1)]);
+        assert_eq!(master.grade(), "5");
+    }
+
+    #[test]
+    fn test_becomes_grand_master_only_within_time() {
+        let mut game = Game::with_seed(0);
+        game.score = 150000;
+
+        let mut master = Master::new();
+        master.level = 998;
+        game.elapsed = Duration::from_secs(12 * 60);
+        assert_eq!(master.update(&mut game, &[put(1)]), ModeProgress::Cleared);
+        assert_eq!(master.grade(), GRAND_MASTER);
+
+        let mut master = Master::new();
+        master.level = 998;
+        game.elapsed = Duration::from_secs(14 * 60);
+        master.update(&mut game, &[put(1)]);
+        assert_eq!(master.grade(), "S9");
+    }
+}
@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use crate::tetris::{
+    game::{Game, GameEvent},
+    mode::{format_time, topped_out, GameMode, ModeProgress},
+    model::{
+        score::{ScoringAction, ScoringReward},
+        tetrimino::Tetrimino,
+    },
+};
+
+/// # ミッションの目標
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Objective {
+    /// この消し方でラインを消す.
+    Clear(ScoringAction),
+    /// このコンボ数まで続けてラインを消す.
+    Combo(usize),
+    /// ホールドから出したIミノでテトリスをする.
+    TetrisFromHold,
+}
+
+impl Objective {
+    pub fn description(&self) -> String {
+        match self {
+            Objective::Clear(action) => match action {
+                ScoringAction::Single => "CLEAR A SINGLE".to_owned(),
+                ScoringAction::Double => "CLEAR A DOUBLE".to_owned(),
+                ScoringAction::Triple => "CLEAR A TRIPLE".to_owned(),
+                ScoringAction::Tetris => "CLEAR A TETRIS".to_owned(),
+                ScoringAction::TSpinSingle => "T-SPIN SINGLE".to_owned(),
+                ScoringAction::TSpinDouble => "T-SPIN DOUBLE".to_owned(),
+                ScoringAction::TSpinTriple => "T-SPIN TRIPLE".to_owned(),
+                ScoringAction::PerfectClear => "PERFECT CLEAR".to_owned(),
+            },
+            Objective::Combo(combo) => format!("{} COMBO", combo),
+            Objective::TetrisFromHold => "TETRIS WITH HELD I".to_owned(),
+        }
+    }
+
+    fn is_achieved_by(&self, mino: Tetrimino, reward: &ScoringReward, from_hold: bool) -> bool {
+        match *self {
+            Objective::Clear(action) => reward.action == action,
+            Objective::Combo(combo) => combo <= reward.combo.saturating_sub(1),
+            Objective::TetrisFromHold => {
+                from_hold && mino == Tetrimino::I && reward.action == ScoringAction::Tetris
+            }
+        }
+    }
+}
+
+/// 易しいものから順に並べた, 目標と制限時間の秒数.
+const MISSIONS: [(Objective, u64); 10] = [
+    (Objective::Clear(ScoringAction::Double), 60),
+    (Objective::Clear(ScoringAction::Triple), 60),
+    (Objective::Combo(2), 60),
+    (Objective::Clear(ScoringAction::TSpinSingle), 90),
+    (Objective::Clear(ScoringAction::Tetris), 60),
+    (Objective::TetrisFromHold, 90),
+    (Objective::Clear(ScoringAction::TSpinDouble), 90),
+    (Objective::Combo(4), 90),
+    (Objective::Clear(ScoringAction::TSpinTriple), 120),
+    (Objective::Clear(ScoringAction::PerfectClear), 120),
+];
+
+/// # ミッション
+///
+/// 制限時間のある目標が1つずつ出され, 達成するたびに難しい目標へ進む.
+/// 時間内に達成できないと終わり, 全て達成するとクリアになる.
+pub struct Mission {
+    current: usize,
+    /// 今の目標が出された, ゲーム開始からの時刻.
+    issued_at: Duration,
+    /// ホールドに入っていると分かっているミノ.
+    held: Option<Tetrimino>,
+    /// 落下中のミノがホールドから出てきたかどうか.
+    dropping_from_hold: bool,
+}
+
+impl Mission {
+    pub fn new() -> Mission {
+        Mission {
+            current: 0,
+            issued_at: Duration::ZERO,
+            held: None,
+            dropping_from_hold: false,
+        }
+    }
+
+    pub fn objective(&self) -> Option<Objective> {
+        MISSIONS.get(self.current).map(|&(objective, _)| objective)
+    }
+
+    pub fn remaining(&self, game: &Game) -> Duration {
+        let limit = MISSIONS
+            .get(self.current)
+            .map(|&(_, secs)| Duration::from_secs(secs))
+            .unwrap_or_default();
+
+        (self.issued_at + limit).saturating_sub(game.elapsed)
+    }
+
+    /// 今の目標を達成したかどうか.
+    fn receive(&mut self, event: &GameEvent) -> bool {
+        match event {
+            GameEvent::Held { mino } => {
+                self.dropping_from_hold = self.held.is_some();
+                self.held = Some(*mino);
+                false
+            }
+            GameEvent::Put { mino, reward, .. } => {
+                let from_hold = self.dropping_from_hold;
+                self.dropping_from_hold = false;
+
+                match (self.objective(), reward) {
+                    (Some(objective), Some(reward)) => {
+                        objective.is_achieved_by(*mino, reward, from_hold)
+                    }
+                    _ => false,
+                }
+            }
+            GameEvent::ToppedOut | GameEvent::RanOut => false,
+        }
+    }
+}
+
+impl GameMode for Mission {
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        if topped_out(events) {
+            return ModeProgress::Failed;
+        }
+
+        for event in events {
+            if self.receive(event) {
+                self.current += 1;
+                self.issued_at = game.elapsed;
+            }
+        }
+
+        match () {
+            _ if MISSIONS.len() <= self.current => ModeProgress::Cleared,
+            _ if self.remaining(game) == Duration::ZERO => ModeProgress::Failed,
+            _ => ModeProgress::Playing,
+        }
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "MISSION",
+                format!(
+                    "{}/{}",
+                    (self.current + 1).min(MISSIONS.len()),
+                    MISSIONS.len()
+                ),
+            ),
+            ("REMAIN", format_time(&self.remaining(game))),
+            ("TIME", format_time(&game.elapsed)),
+        ]
+    }
+
+    fn headline(&self, _: &Game) -> Option<String> {
+        self.objective().map(|objective| objective.description())
+    }
+
+    fn result_title(&self, cleared: bool) -> &'static str {
+        if cleared {
+            "COMPLETE"
+        } else {
+            "MISSION FAILED"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn put(mino: Tetrimino, action: Option<ScoringAction>, combo: usize) -> GameEvent {
+        GameEvent::Put {
+            mino,
+            removed_lines: 1,
+            reward: action.map(|action| ScoringReward::new(action, 0, combo)),
+        }
+    }
+
+    #[test]
+    fn test_advances_on_achieved_objective() {
+        let mut game = Game::with_seed(0);
+        let mut mission = Mission::new();
+
+        let single = put(Tetrimino::T, Some(ScoringAction::Single), 1);
+        assert_eq!(mission.update(&mut game, &[single]), ModeProgress::Playing);
+        assert_eq!(mission.current, 0);
+
+        game.elapsed = Duration::from_secs(10);
+        let double = put(Tetrimino::T, Some(ScoringAction::Double), 1);
+        assert_eq!(mission.update(&mut game, &[double]), ModeProgress::Playing);
+        assert_eq!(
+            mission.objective(),
+            Some(Objective::Clear(ScoringAction::Triple))
+        );
+        assert_eq!(mission.remaining(&game), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_fails_when_time_runs_out() {
+        let mut game = Game::with_seed(0);
+        let mut mission = Mission::new();
+
+        game.elapsed = Duration::from_secs(60);
+        assert_eq!(mission.update(&mut game, &[]), ModeProgress::Failed);
+    }
+
+    #[test]
+    fn test_tetris_must_use_i_from_hold() {
+        let mut mission = Mission::new();
+        mission.current = MISSIONS
+            .iter()
+            .position(|&(objective, _)| objective == Objective::TetrisFromHold)
+            .unwrap();
+        let tetris = || put(Tetrimino::I, Some(ScoringAction::Tetris), 1);
+
+        // 空のホールドに入れたときは, 次のミノはバッグから出てくる
+        assert!(!mission.receive(&GameEvent::Held { mino: Tetrimino::I }));
+        assert!(!mission.receive(&tetris()));
+
+        assert!(!mission.receive(&GameEvent::Held { mino: Tetrimino::O }));
+        assert!(mission.receive(&tetris()));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::tetris::{
+    board::{Field, FIELD_UNIT_HEIGHT, FIELD_UNIT_WIDTH},
+    game::{Game, GameEvent},
+    mode::{topped_out, GameMode, ModeProgress},
+    model::mino_entity::MinoEntity,
+    opener::{Opener, Placement},
+};
+
+/// # 開幕の練習
+///
+/// 選んだ定型の通りにミノを置けているかを1つずつ確かめ, 次に置く場所を示す.
+/// 形に無い場所へ置くとその時点で失敗になる.
+pub struct OpenerTrainer {
+    remaining: Vec<Placement>,
+    placed: usize,
+    /// 前回確かめたときの盤面. 置いたミノのマスを見つけるために比べる.
+    last_field: Field,
+}
+
+impl OpenerTrainer {
+    pub fn new(opener: Opener) -> OpenerTrainer {
+        OpenerTrainer {
+            remaining: opener.placements(),
+            placed: 0,
+            last_field: [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT],
+        }
+    }
+
+    /// 前回から新しく埋まったマス.
+    fn new_cells(&self, field: &Field) -> Vec<(usize, usize)> {
+        let mut cells = Vec::new();
+        for (y, line) in field.iter().enumerate() {
+            for (x, entity) in line.iter().enumerate() {
+                if !entity.is_air() && self.last_field[y][x].is_air() {
+                    cells.push((x, y));
+                }
+            }
+        }
+        // 置き場所と同じく, x, y の順に並べる
+        cells.sort_unstable();
+
+        cells
+    }
+}
+
+impl GameMode for OpenerTrainer {
+    fn setup(&mut self, game: &mut Game) {
+        self.last_field = game.board.confirmed_field;
+    }
+
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        if topped_out(events) {
+            return ModeProgress::Failed;
+        }
+
+        for event in events {
+            if let GameEvent::Put { mino, .. } = event {
+                let cells = self.new_cells(&game.board.confirmed_field);
+                let matched = self
+                    .remaining
+                    .iter()
+                    .position(|placement| placement.mino == *mino && placement.cells == cells);
+
+                match matched {
+                    Some(idx) => {
+                        self.remaining.remove(idx);
+                        self.placed += 1;
+                    }
+                    None => return ModeProgress::Failed,
+                }
+            }
+        }
+        self.last_field = game.board.confirmed_field;
+
+        if self.remaining.is_empty() {
+            ModeProgress::Cleared
+        } else {
+            ModeProgress::Playing
+        }
+    }
+
+    fn hud(&self, _: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("PLACED", self.placed.to_string()),
+            ("LEFT", self.remaining.len().to_string()),
+        ]
+    }
+
+    fn result_title(&self, cleared: bool) -> &'static str {
+        if cleared {
+            "BUILT"
+        } else {
+            "MISSED"
+        }
+    }
+
+    fn guide(&self, game: &Game) -> Option<Placement> {
+        let field = &game.board.confirmed_field;
+
+        self.remaining
+            .iter()
+            .find(|placement| {
+                placement.mino == game.board.dropping && placement.is_available(field)
+            })
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn put(game: &mut Game, placement: &Placement) -> GameEvent {
+        for &(x, y) in &placement.cells {
+            game.board.confirmed_field[y][x] = placement.mino.block().into();
+        }
+
+        GameEvent::Put {
+            mino: placement.mino,
+            removed_lines: 0,
+            reward: None,
+        }
+    }
+
+    #[test]
+    fn test_built_when_all_placed() {
+        let mut game = Game::with_seed(0);
+        let mut trainer = OpenerTrainer::new(Opener::Pco);
+        trainer.setup(&mut game);
+
+        let placements = Opener::Pco.placements();
+        let (last, rest) = placements.split_last().unwrap();
+        for placement in rest {
+            let event = put(&mut game, placement);
+            assert_eq!(trainer.update(&mut game, &[event]), ModeProgress::Playing);
+        }
+        let event = put(&mut game, last);
+        assert_eq!(trainer.update(&mut game, &[event]), ModeProgress::Cleared);
+    }
+
+    #[test]
+    fn test_misses_on_wrong_placement() {
+        let mut game = Game::with_seed(0);
+        let mut trainer = OpenerTrainer::new(Opener::Tki);
+        trainer.setup(&mut game);
+
+        let mut wrong = Opener::Tki.placements()[0].clone();
+        for cell in wrong.cells.iter_mut() {
+            cell.0 += 1;
+        }
+        let event = put(&mut game, &wrong);
+
+        assert_eq!(trainer.update(&mut game, &[event]), ModeProgress::Failed);
+    }
+
+    #[test]
+    fn test_guides_available_placement_of_dropping_mino() {
+        let game = Game::with_seed(0);
+        let trainer = OpenerTrainer::new(Opener::Pco);
+
+        match trainer.guide(&game) {
+            Some(placement) => {
+                assert_eq!(placement.mino, game.board.dropping);
+                assert!(placement.is_available(&game.board.confirmed_field));
+            }
+            None => assert!(trainer
+                .remaining
+                .iter()
+                .filter(|placement| placement.mino == game.board.dropping)
+                .all(|placement| !placement.is_available(&game.board.confirmed_field))),
+        }
+    }
+}
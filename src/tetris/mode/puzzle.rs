@@ -0,0 +1,147 @@
+use crate::tetris::{
+    game::{Game, GameEvent},
+    mode::{topped_out, GameMode, ModeProgress},
+    model::score::ScoringAction,
+    puzzle::PuzzleGoal,
+};
+
+/// # パズル
+///
+/// 決まった盤面と決まった順番のミノで, 目標を達成すればクリアになる.
+pub struct PuzzleMode {
+    goal: PuzzleGoal,
+    lines: usize,
+    pieces: usize,
+}
+
+impl PuzzleMode {
+    pub fn new(goal: PuzzleGoal) -> PuzzleMode {
+        PuzzleMode {
+            goal,
+            lines: 0,
+            pieces: 0,
+        }
+    }
+
+    fn achieves(&self, action: &ScoringAction) -> bool {
+        match self.goal {
+            PuzzleGoal::ClearLines(lines) => lines <= self.lines,
+            PuzzleGoal::TSpinDouble => *action == ScoringAction::TSpinDouble,
+            PuzzleGoal::PerfectClear(pieces) => {
+                *action == ScoringAction::PerfectClear && self.pieces <= pieces
+            }
+        }
+    }
+
+    /// これ以上ミノを置いても目標を達成できないかどうか.
+    fn gives_up(&self, events: &[GameEvent]) -> bool {
+        let ran_out = events
+            .iter()
+            .any(|event| matches!(event, GameEvent::RanOut));
+
+        match self.goal {
+            _ if topped_out(events) || ran_out => true,
+            PuzzleGoal::PerfectClear(pieces) => pieces <= self.pieces,
+            _ => false,
+        }
+    }
+}
+
+impl GameMode for PuzzleMode {
+    fn update(&mut self, _: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        for event in events {
+            if let GameEvent::Put {
+                removed_lines,
+                reward,
+                ..
+            } = event
+            {
+                self.pieces += 1;
+                self.lines += removed_lines;
+
+                if let Some(reward) = reward {
+                    if self.achieves(&reward.action) {
+                        return ModeProgress::Cleared;
+                    }
+                }
+            }
+        }
+
+        if self.gives_up(events) {
+            ModeProgress::Failed
+        } else {
+            ModeProgress::Playing
+        }
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("LINES", self.lines.to_string()),
+            ("PIECES", (game.bag.remaining() + 1).to_string()),
+        ]
+    }
+
+    fn result_title(&self, cleared: bool) -> &'static str {
+        if cleared {
+            "SOLVED"
+        } else {
+            "FAILED"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tetris::puzzle::PuzzlePack;
+
+    fn bundled(index: usize) -> (Game, PuzzleMode) {
+        let pack: PuzzlePack =
+            serde_json::from_str(include_str!("../../../resources/puzzles/basics.json")).unwrap();
+        let puzzle = &pack.puzzles[index];
+
+        (puzzle.create_game(), PuzzleMode::new(puzzle.goal))
+    }
+
+    fn put(game: &mut Game, mode: &mut PuzzleMode) -> ModeProgress {
+        game.hard_drop();
+        game.put_and_spawn();
+        game.remove_lines();
+
+        let events = game.take_events();
+        mode.update(game, &events)
+    }
+
+    #[test]
+    fn test_solves_t_spin_double() {
+        let (mut game, mut mode) = bundled(2);
+
+        game.spin_left();
+        while game.move_left() {}
+        game.move_right();
+        // 溝の底まで落としてから回して入れる
+        while game.drop_one().is_none() && !game.board.dropping_mino_is_on_ground() {}
+        game.spin_left();
+
+        assert_eq!(put(&mut game, &mut mode), ModeProgress::Cleared);
+    }
+
+    #[test]
+    fn test_solves_perfect_clear() {
+        let (mut game, mut mode) = bundled(3);
+
+        while game.move_right() {}
+        assert_eq!(put(&mut game, &mut mode), ModeProgress::Playing);
+
+        while game.move_right() {}
+        assert_eq!(put(&mut game, &mut mode), ModeProgress::Cleared);
+    }
+
+    #[test]
+    fn test_fails_when_minos_run_out() {
+        let (mut game, mut mode) = bundled(0);
+
+        while game.move_left() {}
+        assert_eq!(put(&mut game, &mut mode), ModeProgress::Failed);
+    }
+}
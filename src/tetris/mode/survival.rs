@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    scene::timer::Timer,
+    tetris::{
+        board::FIELD_UNIT_WIDTH,
+        game::{Game, GameEvent},
+        mode::{format_time, topped_out, GameMode, ModeProgress},
+    },
+};
+
+/// おじゃまラインがせり上がるたびに, 次までの間隔に掛ける割合.
+const ACCELERATION: f64 = 0.95;
+const MIN_INTERVAL: Duration = Duration::from_millis(1000);
+/// ミノの順番と同じシードから, 別の乱数列を作るために混ぜる値.
+const SEED_SALT: u64 = 0x7375_7276;
+
+/// # サバイバル
+///
+/// 次第に速くなる間隔でせり上がるおじゃまラインに耐え, 積み上がるまでの時間を競う.
+pub struct Survival {
+    timer: Timer,
+    interval: Duration,
+    last_elapsed: Duration,
+    raised_rows: usize,
+    rng: StdRng,
+}
+
+impl Survival {
+    /// 同じシードからは, 同じ位置に穴の空いたおじゃまラインがせり上がる.
+    pub fn new(interval: Duration, seed: u64) -> Survival {
+        Survival {
+            timer: Timer::single(interval),
+            interval,
+            last_elapsed: Duration::ZERO,
+            raised_rows: 0,
+            rng: StdRng::seed_from_u64(seed ^ SEED_SALT),
+        }
+    }
+
+    /// せり上げるたびに間隔を縮め, 次のせり上げを待つタイマーに差し替える.
+    fn accelerate(&mut self) {
+        self.interval = self.interval.mul_f64(ACCELERATION).max(MIN_INTERVAL);
+        self.timer = Timer::single(self.interval);
+    }
+}
+
+impl GameMode for Survival {
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        if topped_out(events) {
+            return ModeProgress::Failed;
+        }
+
+        self.timer
+            .elapse(game.elapsed.saturating_sub(self.last_elapsed));
+        self.last_elapsed = game.elapsed;
+
+        if self.timer.consume_if_beep() {
+            self.accelerate();
+            self.raised_rows += 1;
+
+            let hole_x = self.rng.gen_range(0..FIELD_UNIT_WIDTH);
+            if !game.raise_garbage(1, hole_x) {
+                return ModeProgress::Failed;
+            }
+        }
+
+        ModeProgress::Playing
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("TIME", format_time(&game.elapsed)),
+            ("RAISED", self.raised_rows.to_string()),
+            ("NEXT", format!("{:.1}s", self.interval.as_secs_f32())),
+            ("LINES", game.removed_line_count.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tetris::{board::FIELD_UNIT_HEIGHT, model::mino_entity::MinoEntity};
+
+    #[test]
+    fn test_raises_garbage_faster_and_faster() {
+        let mut game = Game::with_seed(0);
+        let mut survival = Survival::new(Duration::from_secs(10), 0);
+
+        game.elapsed = Duration::from_secs(9);
+        assert_eq!(survival.update(&mut game, &[]), ModeProgress::Playing);
+        assert_eq!(survival.raised_rows, 0);
+
+        game.elapsed = Duration::from_secs(10);
+        assert_eq!(survival.update(&mut game, &[]), ModeProgress::Playing);
+        assert_eq!(survival.raised_rows, 1);
+        assert_eq!(survival.interval, Duration::from_millis(9500));
+        assert!(game.board.confirmed_field[FIELD_UNIT_HEIGHT - 1].contains(&MinoEntity::GARBAGE));
+
+        game.elapsed = Duration::from_millis(19500);
+        assert_eq!(survival.update(&mut game, &[]), ModeProgress::Playing);
+        assert_eq!(survival.raised_rows, 2);
+    }
+
+    #[test]
+    fn test_interval_never_falls_below_minimum() {
+        let mut survival = Survival::new(MIN_INTERVAL, 0);
+        survival.accelerate();
+
+        assert_eq!(survival.interval, MIN_INTERVAL);
+    }
+
+    #[test]
+    fn test_fails_when_pushed_out() {
+        let mut game = Game::with_seed(0);
+        let mut survival = Survival::new(MIN_INTERVAL, 0);
+        game.board.confirmed_field[0][0] = MinoEntity::GARBAGE;
+
+        game.elapsed = MIN_INTERVAL;
+        assert_eq!(survival.update(&mut game, &[]), ModeProgress::Failed);
+    }
+}
@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use crate::tetris::{
+    game::{Game, GameEvent},
+    mode::{format_time, topped_out, GameMode, ModeProgress},
+};
+
+/// # ウルトラ
+///
+/// 制限時間の中で, できるだけ多くの得点を稼ぐ.
+pub struct Ultra {
+    limit: Duration,
+}
+
+impl Ultra {
+    pub fn new(limit: Duration) -> Ultra {
+        Ultra { limit }
+    }
+
+    pub fn remaining(&self, game: &Game) -> Duration {
+        self.limit.saturating_sub(game.elapsed)
+    }
+}
+
+impl GameMode for Ultra {
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        match () {
+            _ if topped_out(events) => ModeProgress::Failed,
+            _ if self.limit <= game.elapsed => ModeProgress::Cleared,
+            _ => ModeProgress::Playing,
+        }
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("REMAIN", format_time(&self.remaining(game))),
+            ("SCORE", game.score.to_string()),
+            ("LINES", game.removed_line_count.to_string()),
+        ]
+    }
+
+    fn result_title(&self, cleared: bool) -> &'static str {
+        if cleared {
+            "TIME UP"
+        } else {
+            "GAME OVER"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ends_on_timer() {
+        let mut game = Game::with_seed(0);
+        let mut ultra = Ultra::new(Duration::from_secs(120));
+
+        game.elapsed = Duration::from_secs(119);
+        assert_eq!(ultra.update(&mut game, &[]), ModeProgress::Playing);
+        assert_eq!(ultra.remaining(&game), Duration::from_secs(1));
+
+        game.elapsed = Duration::from_secs(120);
+        assert_eq!(ultra.update(&mut game, &[]), ModeProgress::Cleared);
+        assert_eq!(ultra.remaining(&game), Duration::ZERO);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::tetris::{
+    game::{Game, GameEvent},
+    mode::{topped_out, GameMode, ModeProgress},
+};
+
+/// # 禅
+///
+/// 時間に追われず, 積み上がっても盤面が片付くだけで終わらない.
+pub struct Zen;
+
+impl GameMode for Zen {
+    fn update(&mut self, game: &mut Game, events: &[GameEvent]) -> ModeProgress {
+        if topped_out(events) {
+            game.clear_stack();
+        }
+
+        ModeProgress::Playing
+    }
+
+    fn hud(&self, game: &Game) -> Vec<(&'static str, String)> {
+        vec![
+            ("SCORE", game.score.to_string()),
+            ("LINES", game.removed_line_count.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tetris::{board::FIELD_UNIT_HEIGHT, model::mino_entity::MinoEntity};
+
+    #[test]
+    fn test_clears_stack_instead_of_ending() {
+        let mut game = Game::with_seed(0);
+        game.board.confirmed_field[FIELD_UNIT_HEIGHT - 1][0] = MinoEntity::GARBAGE;
+
+        assert_eq!(
+            Zen.update(&mut game, &[GameEvent::ToppedOut]),
+            ModeProgress::Playing
+        );
+        assert!(game
+            .board
+            .confirmed_field
+            .iter()
+            .all(|line| line.iter().all(|entity| entity.is_air())));
+    }
+}
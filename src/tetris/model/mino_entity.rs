@@ -13,6 +13,9 @@ pub enum MinoEntity {
     GREEN,
     RED,
 
+    /// 相手の攻撃によってせり上がってきたブロック
+    GARBAGE,
+
     AIR,
 }
 
@@ -28,6 +31,7 @@ impl MinoEntity {
             MinoEntity::ORANGE => Some(ORANGE),
             MinoEntity::GREEN => Some(GREEN),
             MinoEntity::RED => Some(RED),
+            MinoEntity::GARBAGE => Some(GRAY),
             MinoEntity::AIR => None,
         }
     }
@@ -49,6 +53,7 @@ impl Into<MinoEntity> for MinoBlock {
             MinoBlock::ORANGE => ORANGE,
             MinoBlock::GREEN => GREEN,
             MinoBlock::RED => RED,
+            MinoBlock::GRAY => GARBAGE,
         }
     }
 }
@@ -1,21 +1,27 @@
+use serde::{Deserialize, Serialize};
 use ScoringAction::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ScoringReward {
     pub action: ScoringAction,
-    pub with_back_to_back: bool,
+    /// 直前まで Back to Back の対象になる消し方が続いた回数.
+    pub back_to_back: usize,
     pub combo: usize,
 }
 
 impl ScoringReward {
-    pub fn new(action: ScoringAction, with_back_to_back: bool, combo: usize) -> ScoringReward {
+    pub fn new(action: ScoringAction, back_to_back: usize, combo: usize) -> ScoringReward {
         ScoringReward {
             action,
-            with_back_to_back,
+            back_to_back,
             combo,
         }
     }
 
+    pub fn with_back_to_back(&self) -> bool {
+        0 < self.back_to_back
+    }
+
     pub fn score(&self) -> usize {
         let action_score = match self.action {
             Single => 100,
@@ -40,7 +46,7 @@ impl ScoringReward {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ScoringAction {
     Single,
     Double,
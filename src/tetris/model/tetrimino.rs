@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
 use MinoRotation::*;
 use Tetrimino::*;
 
@@ -12,7 +13,7 @@ use crate::{
 type MinoShape = Vec<Vec<bool>>;
 
 /// テトリスにおいて落下してくる一塊のブロック群を表現する
-#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Tetrimino {
     T,
     S,
@@ -296,7 +297,7 @@ impl Tetrimino {
 }
 
 /// テトリミノのもつブロックの種類を表現する
-#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum MinoBlock {
     AQUA,
     YELLOW,
@@ -305,6 +306,9 @@ pub enum MinoBlock {
     ORANGE,
     GREEN,
     RED,
+
+    /// テトリミノには属さない, おじゃまブロックの色
+    GRAY,
 }
 
 /// テトリミノ回転時のオフセットを表現する
@@ -0,0 +1,244 @@
+use std::collections::BTreeSet;
+
+use crate::tetris::{
+    board::{Field, FIELD_UNIT_HEIGHT},
+    model::tetrimino::Tetrimino,
+};
+
+/// # 開幕の定型
+///
+/// 最初の数巡のミノで組み上げる, よく知られたTスピンやパーフェクトクリアの形.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Opener {
+    Tki,
+    DtCannon,
+    CSpin,
+    Pco,
+}
+
+impl Opener {
+    pub fn all() -> Vec<Opener> {
+        vec![Opener::Tki, Opener::DtCannon, Opener::CSpin, Opener::Pco]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Opener::Tki => "TKI",
+            Opener::DtCannon => "DT Cannon",
+            Opener::CSpin => "C-Spin",
+            Opener::Pco => "PCO",
+        }
+    }
+
+    /// 組み上がった形を, 盤面の下から積まれた行として上から順に並べる.
+    /// ミノの名前はそのミノを置くマスを表し, 同じミノ同士は隣り合わない.
+    #[rustfmt::skip]
+    fn rows(&self) -> &'static [&'static str] {
+        match self {
+            Opener::Tki => &[
+                "L..IIIIOOJ",
+                "L...TZZOOJ",
+                "LL.TTTZZJJ",
+            ],
+            Opener::DtCannon => &[
+                "........OO",
+                "I....TZZOO",
+                "IL..TTTZZL",
+                "IL...SSLLL",
+                "ILL.SSIIII",
+            ],
+            Opener::CSpin => &[
+                "IIII......",
+                "OOZZTTT..J",
+                "OOLZZT...J",
+                "LLLIIII.JJ",
+            ],
+            Opener::Pco => &[
+                "....SIIIIZ",
+                "....SSOOZZ",
+                "....JSOOZL",
+                "....JJJLLL",
+            ],
+        }
+    }
+
+    /// 形をミノ1つずつの置き場所に分ける. 順番は上の行から読んだ順になる.
+    pub fn placements(&self) -> Vec<Placement> {
+        let rows = self.rows();
+        let top = FIELD_UNIT_HEIGHT - rows.len();
+        let cells: Vec<Vec<char>> = rows.iter().map(|row| row.chars().collect()).collect();
+
+        let mut visited = BTreeSet::new();
+        let mut placements = Vec::new();
+
+        for (y, line) in cells.iter().enumerate() {
+            for (x, &c) in line.iter().enumerate() {
+                if c == '.' || visited.contains(&(x, y)) {
+                    continue;
+                }
+
+                // 同じ文字で繋がったマスを1つの置き場所とする
+                let mut group = vec![(x, y)];
+                let mut stack = vec![(x, y)];
+                visited.insert((x, y));
+                while let Some((cx, cy)) = stack.pop() {
+                    let neighbors = [
+                        (cx.wrapping_sub(1), cy),
+                        (cx + 1, cy),
+                        (cx, cy.wrapping_sub(1)),
+                        (cx, cy + 1),
+                    ];
+                    for (nx, ny) in neighbors {
+                        let same = cells.get(ny).and_then(|line| line.get(nx)) == Some(&c);
+                        if same && visited.insert((nx, ny)) {
+                            group.push((nx, ny));
+                            stack.push((nx, ny));
+                        }
+                    }
+                }
+
+                let mut cells: Vec<(usize, usize)> =
+                    group.into_iter().map(|(x, y)| (x, top + y)).collect();
+                cells.sort_unstable();
+
+                placements.push(Placement {
+                    mino: mino_of(c),
+                    cells,
+                });
+            }
+        }
+
+        placements
+    }
+}
+
+fn mino_of(c: char) -> Tetrimino {
+    match c {
+        'T' => Tetrimino::T,
+        'S' => Tetrimino::S,
+        'Z' => Tetrimino::Z,
+        'L' => Tetrimino::L,
+        'J' => Tetrimino::J,
+        'O' => Tetrimino::O,
+        'I' => Tetrimino::I,
+        _ => panic!("an opener has an unknown mino '{}'", c),
+    }
+}
+
+/// # ミノの置き場所
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub mino: Tetrimino,
+    /// 盤面上の `(x, y)` を並べたもの.
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Placement {
+    /// 今の盤面でこの場所にミノを置けるかどうか.
+    /// 上から落として届き, 少なくとも1マスが床かブロックの上に乗っていなければならない.
+    pub fn is_available(&self, field: &Field) -> bool {
+        let reachable = self
+            .cells
+            .iter()
+            .all(|&(x, y)| (0..=y).all(|above| field[above][x].is_air()));
+        let supported = self
+            .cells
+            .iter()
+            .any(|&(x, y)| y + 1 == FIELD_UNIT_HEIGHT || !field[y + 1][x].is_air());
+
+        reachable && supported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::tetris::{
+        board::FIELD_UNIT_WIDTH,
+        model::{mino_entity::MinoEntity, tetrimino::MinoRotation},
+    };
+
+    fn normalize(cells: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let min_x = cells.iter().map(|&(x, _)| x).min().unwrap();
+        let min_y = cells.iter().map(|&(_, y)| y).min().unwrap();
+        let mut cells: Vec<_> = cells.iter().map(|&(x, y)| (x - min_x, y - min_y)).collect();
+        cells.sort_unstable();
+
+        cells
+    }
+
+    fn has_shape_of(placement: &Placement) -> bool {
+        let rotations = [
+            MinoRotation::Clockwise,
+            MinoRotation::Clockwise90,
+            MinoRotation::Clockwise180,
+            MinoRotation::Clockwise270,
+        ];
+        let shapes = placement.mino.shapes();
+
+        rotations.iter().any(|rotation| {
+            let shape: Vec<(usize, usize)> = shapes[rotation]
+                .iter()
+                .enumerate()
+                .flat_map(|(y, line)| {
+                    line.iter()
+                        .enumerate()
+                        .filter(|(_, &exists)| exists)
+                        .map(move |(x, _)| (x, y))
+                })
+                .collect();
+
+            normalize(&shape) == normalize(&placement.cells)
+        })
+    }
+
+    /// 置ける場所から順に置いていき, 全て置ける順番があるかを探す.
+    fn can_build(field: &Field, remaining: &[Placement]) -> bool {
+        if remaining.is_empty() {
+            return true;
+        }
+
+        remaining.iter().enumerate().any(|(idx, placement)| {
+            if !placement.is_available(field) {
+                return false;
+            }
+
+            let mut field = *field;
+            for &(x, y) in &placement.cells {
+                field[y][x] = placement.mino.block().into();
+            }
+            let mut rest = remaining.to_vec();
+            rest.remove(idx);
+
+            can_build(&field, &rest)
+        })
+    }
+
+    #[test_case(Opener::Tki)]
+    #[test_case(Opener::DtCannon)]
+    #[test_case(Opener::CSpin)]
+    #[test_case(Opener::Pco)]
+    fn test_opener_is_buildable(opener: Opener) {
+        let placements = opener.placements();
+
+        assert!(placements.iter().all(has_shape_of));
+        assert!(can_build(
+            &[[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT],
+            &placements
+        ));
+    }
+
+    #[test]
+    fn test_overhang_is_not_available_until_supported() {
+        let placements = Opener::Tki.placements();
+        let overhang = placements
+            .iter()
+            .find(|placement| placement.mino == Tetrimino::I)
+            .unwrap();
+
+        let field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+        assert!(!overhang.is_available(&field));
+    }
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+use crate::tetris::{
+    board::{Field, FIELD_UNIT_HEIGHT, FIELD_UNIT_WIDTH},
+    game::Game,
+    model::{mino_entity::MinoEntity, tetrimino::Tetrimino},
+    rule::Rule,
+};
+
+/// # パズルのパック
+///
+/// `resources/puzzles/` に置かれた1つのJSONファイルが1つのパックになる.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PuzzlePack {
+    pub name: String,
+    pub puzzles: Vec<Puzzle>,
+}
+
+impl PuzzlePack {
+    /// 解いたパズルを記録するときの, パックをまたいで一意な名前.
+    pub fn key(&self, index: usize) -> String {
+        format!("{}/{}", self.name, self.puzzles[index].name)
+    }
+
+    /// 全てのパズルが遊べる形になっているかを確かめる.
+    pub fn validate(&self) -> Result<(), String> {
+        self.puzzles.iter().try_for_each(|puzzle| {
+            if puzzle.sequence.is_empty() {
+                return Err(format!("{} has no minos", puzzle.name));
+            }
+
+            puzzle.field().map(|_| ())
+        })
+    }
+}
+
+/// # パズル
+///
+/// 決まった盤面と決まった順番のミノで, 目標の達成を目指す.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Puzzle {
+    pub name: String,
+
+    /// 盤面の下から積まれた行を, 上から順に並べる.
+    /// `.` は空気, `X` はおじゃまブロック, ミノの名前はそのミノの色のブロックを表す.
+    pub field: Vec<String>,
+
+    pub sequence: Vec<Tetrimino>,
+
+    #[serde(default)]
+    pub hold: bool,
+
+    pub goal: PuzzleGoal,
+}
+
+impl Puzzle {
+    pub fn field(&self) -> Result<Field, String> {
+        if FIELD_UNIT_HEIGHT < self.field.len() {
+            return Err(format!("{} has too many rows", self.name));
+        }
+
+        let mut field = [[MinoEntity::AIR; FIELD_UNIT_WIDTH]; FIELD_UNIT_HEIGHT];
+        let top = FIELD_UNIT_HEIGHT - self.field.len();
+
+        for (y, row) in self.field.iter().enumerate() {
+            if row.chars().count() != FIELD_UNIT_WIDTH {
+                return Err(format!(
+                    "{} has a row not {} wide",
+                    self.name, FIELD_UNIT_WIDTH
+                ));
+            }
+
+            for (x, c) in row.chars().enumerate() {
+                field[top + y][x] = match c {
+                    '.' => MinoEntity::AIR,
+                    'X' => MinoEntity::GARBAGE,
+                    'T' => Tetrimino::T.block().into(),
+                    'S' => Tetrimino::S.block().into(),
+                    'Z' => Tetrimino::Z.block().into(),
+                    'L' => Tetrimino::L.block().into(),
+                    'J' => Tetrimino::J.block().into(),
+                    'O' => Tetrimino::O.block().into(),
+                    'I' => Tetrimino::I.block().into(),
+                    _ => return Err(format!("{} has an unknown block '{}'", self.name, c)),
+                };
+            }
+        }
+
+        Ok(field)
+    }
+
+    /// パズルの盤面とミノで始まるゲーム. 読み込んだときに確かめたパズルでなければならない.
+    pub fn create_game(&self) -> Game {
+        let rule = Rule {
+            hold: self.hold,
+            ..Rule::guideline()
+        };
+
+        let mut game = Game::with_sequence(rule, self.sequence.clone());
+        game.board.confirmed_field = self.field().expect("the puzzle must be validated");
+
+        game
+    }
+}
+
+/// # パズルの目標
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PuzzleGoal {
+    /// このライン数を消す.
+    ClearLines(usize),
+    TSpinDouble,
+    /// このミノ数までにパーフェクトクリアする.
+    PerfectClear(usize),
+}
+
+impl PuzzleGoal {
+    pub fn description(&self) -> String {
+        match self {
+            PuzzleGoal::ClearLines(lines) => format!("CLEAR {} LINES", lines),
+            PuzzleGoal::TSpinDouble => "T-SPIN DOUBLE".to_owned(),
+            PuzzleGoal::PerfectClear(pieces) => format!("PERFECT CLEAR IN {}", pieces),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_packs_are_valid() {
+        let json = include_str!("../../resources/puzzles/basics.json");
+        let pack: PuzzlePack = serde_json::from_str(json).unwrap();
+
+        assert!(!pack.puzzles.is_empty());
+        assert_eq!(pack.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_field_is_stacked_from_bottom() {
+        let json = r#"{
+            "name": "test",
+            "field": ["X.........", "TTTOOOOOO."],
+            "sequence": ["I"],
+            "goal": { "clear_lines": 1 }
+        }"#;
+        let puzzle: Puzzle = serde_json::from_str(json).unwrap();
+        let field = puzzle.field().unwrap();
+
+        assert!(!puzzle.hold);
+        assert_eq!(puzzle.goal, PuzzleGoal::ClearLines(1));
+        assert!(field[FIELD_UNIT_HEIGHT - 2][0] == MinoEntity::GARBAGE);
+        assert!(field[FIELD_UNIT_HEIGHT - 2][1].is_air());
+        assert!(field[FIELD_UNIT_HEIGHT - 1][0] == Tetrimino::T.block().into());
+        assert!(field[FIELD_UNIT_HEIGHT - 1][9].is_air());
+        assert!(field[FIELD_UNIT_HEIGHT - 3].iter().all(|e| e.is_air()));
+    }
+
+    #[test]
+    fn test_rejects_malformed_rows() {
+        let json = r#"{
+            "name": "test",
+            "field": ["XXXX"],
+            "sequence": ["I"],
+            "goal": "t_spin_double"
+        }"#;
+        let puzzle: Puzzle = serde_json::from_str(json).unwrap();
+
+        assert!(puzzle.field().is_err());
+    }
+}
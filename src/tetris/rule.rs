@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use crate::tetris::model::score::ScoringReward;
+
+/// 1フレームの長さ. クラシックやマスターの重力と遅延はフレーム単位で決まっている.
+pub const FRAME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// # ルール
+///
+/// ホールドやゴースト, 回転補正の有無など, モードごとに変わる操作と表示の決まり.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub randomizer: Randomizer,
+    pub scoring: Scoring,
+    pub hold: bool,
+    pub hard_drop: bool,
+    pub ghost: bool,
+    pub wall_kicks: bool,
+    /// 表示するネクストの数.
+    pub next_amount: usize,
+    /// 押しっぱなしにしてからリピートが始まるまでの時間.
+    pub das: Duration,
+    /// リピートが始まってからの入力の間隔.
+    pub arr: Duration,
+    /// 置かれたブロックが見えなくなるまでの時間. `None` のときは消えない.
+    pub fade: Option<Duration>,
+    /// ミノの1マスを2x2マスの大きさにして, 半分の幅の盤面で遊ぶ.
+    pub big: bool,
+}
+
+impl Rule {
+    pub fn guideline() -> Rule {
+        Rule {
+            randomizer: Randomizer::SevenBag,
+            scoring: Scoring::Guideline,
+            hold: true,
+            hard_drop: true,
+            ghost: true,
+            wall_kicks: true,
+            next_amount: 5,
+            das: Duration::from_millis(400),
+            arr: Duration::from_millis(30),
+            fade: None,
+            big: false,
+        }
+    }
+
+    /// ファミコン版に倣った, ホールドもゴーストも回転補正もないルール.
+    pub fn classic() -> Rule {
+        Rule {
+            randomizer: Randomizer::Classic,
+            scoring: Scoring::Classic,
+            hold: false,
+            hard_drop: false,
+            ghost: false,
+            wall_kicks: false,
+            next_amount: 1,
+            das: FRAME * 16,
+            arr: FRAME * 6,
+            fade: None,
+            big: false,
+        }
+    }
+}
+
+/// # ミノの出現順
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Randomizer {
+    /// 七種一巡の法則に従う.
+    SevenBag,
+    /// ファミコン版と同じく, 直前と同じミノのときに一度だけ引き直す.
+    Classic,
+}
+
+/// # 得点の計算
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Scoring {
+    Guideline,
+    /// 消したライン数だけで決まり, Tスピンやコンボの加点はない.
+    Classic,
+}
+
+impl Scoring {
+    /// 倍率を掛ける前の, ラインを消したときの得点.
+    pub fn score(&self, reward: &ScoringReward, removed_lines: usize) -> usize {
+        match self {
+            Scoring::Guideline => reward.score(),
+            Scoring::Classic => match removed_lines {
+                1 => 40,
+                2 => 100,
+                3 => 300,
+                4 => 1200,
+                _ => 0,
+            },
+        }
+    }
+
+    /// ソフトドロップで `rows` マス押し下げたときの得点. クラシックでも押し下げた行数が得点になる.
+    pub fn soft_drop_score(&self, rows: usize) -> usize {
+        match self {
+            Scoring::Guideline => rows,
+            Scoring::Classic => rows,
+        }
+    }
+
+    /// ハードドロップで1マス落とすごとの得点.
+    pub fn hard_drop_score(&self) -> usize {
+        match self {
+            Scoring::Guideline => 2,
+            Scoring::Classic => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::tetris::model::score::ScoringAction;
+
+    #[test_case(ScoringAction::Single, 1, 40)]
+    #[test_case(ScoringAction::Tetris, 4, 1200)]
+    #[test_case(ScoringAction::TSpinDouble, 2, 100)]
+    fn test_classic_scoring_ignores_actions(action: ScoringAction, lines: usize, expected: usize) {
+        let reward = ScoringReward::new(action, 1, 5);
+
+        assert_eq!(Scoring::Classic.score(&reward, lines), expected);
+    }
+
+    #[test_case(Scoring::Guideline, 3 => 3)]
+    #[test_case(Scoring::Classic, 0 => 0)]
+    #[test_case(Scoring::Classic, 3 => 3)]
+    fn test_soft_drop_score_counts_pushed_rows(scoring: Scoring, rows: usize) -> usize {
+        scoring.soft_drop_score(rows)
+    }
+}
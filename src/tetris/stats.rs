@@ -0,0 +1,238 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::tetris::{
+    attack::AttackTable,
+    game::GameEvent,
+    model::{
+        score::{ScoringAction, ScoringReward},
+        tetrimino::Tetrimino,
+    },
+};
+
+/// # プレイ統計
+///
+/// ゲームイベントと操作の入力から, プレイの内容を集計する.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayStats {
+    /// 設置したミノの数.
+    pub pieces: usize,
+
+    /// ミノを操作するために押したキーの数. 押しっぱなしによるリピートは数えない.
+    pub inputs: usize,
+
+    pub holds: usize,
+    pub lines: usize,
+
+    /// 相手に送ることのできた攻撃のライン数.
+    pub attack: usize,
+
+    /// ラインを消した種類ごとの回数.
+    pub clears: BTreeMap<ScoringAction, usize>,
+
+    pub max_combo: usize,
+    pub max_back_to_back: usize,
+
+    /// テトリミノごとの出現数.
+    pub distribution: BTreeMap<Tetrimino, usize>,
+
+    /// 最小より多い入力で置いたミノの数.
+    pub finesse_faults: usize,
+
+    combo: usize,
+    back_to_back: usize,
+
+    #[serde(skip)]
+    attack_table: AttackTable,
+}
+
+impl PlayStats {
+    pub fn new() -> PlayStats {
+        PlayStats::default()
+    }
+
+    /// 攻撃のライン数を指定したテーブルで数える.
+    pub fn with_attack_table(attack_table: AttackTable) -> PlayStats {
+        PlayStats {
+            attack_table,
+            ..PlayStats::default()
+        }
+    }
+
+    pub fn count_input(&mut self) {
+        self.inputs += 1;
+    }
+
+    pub fn count_finesse_fault(&mut self) {
+        self.finesse_faults += 1;
+    }
+
+    pub fn receive(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::Held { .. } => {
+                self.holds += 1;
+            }
+            GameEvent::Put {
+                mino,
+                removed_lines,
+                reward,
+            } => {
+                self.pieces += 1;
+                self.lines += removed_lines;
+                *self.distribution.entry(*mino).or_insert(0) += 1;
+
+                match reward {
+                    Some(reward) => self.receive_reward(reward),
+                    None => self.combo = 0,
+                }
+            }
+            GameEvent::ToppedOut | GameEvent::RanOut => (),
+        }
+    }
+
+    fn receive_reward(&mut self, reward: &ScoringReward) {
+        *self.clears.entry(reward.action).or_insert(0) += 1;
+        self.attack += self.attack_table.attack(reward);
+
+        self.combo = reward.combo.saturating_sub(1);
+        self.max_combo = self.max_combo.max(self.combo);
+
+        self.back_to_back = match () {
+            _ if !reward.action.is_subjected_to_back_to_back() => 0,
+            _ if reward.with_back_to_back() => self.back_to_back + 1,
+            _ => 0,
+        };
+        self.max_back_to_back = self.max_back_to_back.max(self.back_to_back);
+    }
+
+    /// 現在のコンボ数. ラインを消さずに設置すると0に戻る.
+    pub fn combo(&self) -> usize {
+        self.combo
+    }
+
+    /// 現在 Back to Back が連続している回数.
+    pub fn back_to_back(&self) -> usize {
+        self.back_to_back
+    }
+
+    pub fn clear_count(&self, action: &ScoringAction) -> usize {
+        self.clears.get(action).copied().unwrap_or(0)
+    }
+
+    pub fn mino_count(&self, mino: &Tetrimino) -> usize {
+        self.distribution.get(mino).copied().unwrap_or(0)
+    }
+
+    /// Pieces Per Second
+    pub fn pps(&self, elapsed: &Duration) -> f32 {
+        per(self.pieces as f32, elapsed.as_secs_f32())
+    }
+
+    /// Keys Per Piece
+    pub fn kpp(&self) -> f32 {
+        per(self.inputs as f32, self.pieces as f32)
+    }
+
+    /// Attack Per Minute
+    pub fn apm(&self, elapsed: &Duration) -> f32 {
+        per(self.attack as f32, elapsed.as_secs_f32() / 60.)
+    }
+}
+
+fn per(numerator: f32, denominator: f32) -> f32 {
+    if denominator > 0. {
+        numerator / denominator
+    } else {
+        0.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn put(mino: Tetrimino, reward: Option<ScoringReward>) -> GameEvent {
+        let removed_lines = match reward.as_ref().map(|r| r.action) {
+            Some(ScoringAction::Single) => 1,
+            Some(ScoringAction::Tetris) => 4,
+            Some(ScoringAction::TSpinDouble) => 2,
+            _ => 0,
+        };
+
+        GameEvent::Put {
+            mino,
+            removed_lines,
+            reward,
+        }
+    }
+
+    #[test]
+    fn test_counts_pieces_and_distribution() {
+        let mut stats = PlayStats::new();
+        stats.receive(&put(Tetrimino::T, None));
+        stats.receive(&put(Tetrimino::T, None));
+        stats.receive(&put(Tetrimino::I, None));
+        stats.receive(&GameEvent::Held { mino: Tetrimino::O });
+
+        assert_eq!(stats.pieces, 3);
+        assert_eq!(stats.holds, 1);
+        assert_eq!(stats.mino_count(&Tetrimino::T), 2);
+        assert_eq!(stats.mino_count(&Tetrimino::I), 1);
+        assert_eq!(stats.mino_count(&Tetrimino::O), 0);
+    }
+
+    #[test]
+    fn test_combo_and_back_to_back() {
+        let mut stats = PlayStats::new();
+        stats.receive(&put(
+            Tetrimino::I,
+            Some(ScoringReward::new(ScoringAction::Tetris, 0, 1)),
+        ));
+        stats.receive(&put(
+            Tetrimino::T,
+            Some(ScoringReward::new(ScoringAction::TSpinDouble, 1, 2)),
+        ));
+        stats.receive(&put(
+            Tetrimino::I,
+            Some(ScoringReward::new(ScoringAction::Tetris, 2, 3)),
+        ));
+        stats.receive(&put(Tetrimino::O, None));
+
+        assert_eq!(stats.lines, 10);
+        assert_eq!(stats.clear_count(&ScoringAction::Tetris), 2);
+        assert_eq!(stats.clear_count(&ScoringAction::TSpinDouble), 1);
+        assert_eq!(stats.max_combo, 2);
+        assert_eq!(stats.combo(), 0);
+        assert_eq!(stats.max_back_to_back, 2);
+        assert_eq!(stats.attack, 4 + (4 + 1) + (4 + 1 + 1));
+    }
+
+    #[test]
+    fn test_attack_table() {
+        let mut stats = PlayStats::with_attack_table(AttackTable {
+            tetris: 6,
+            ..AttackTable::guideline()
+        });
+        stats.receive(&put(
+            Tetrimino::I,
+            Some(ScoringReward::new(ScoringAction::Tetris, 0, 1)),
+        ));
+
+        assert_eq!(stats.attack, 6);
+    }
+
+    #[test]
+    fn test_rates() {
+        let mut stats = PlayStats::new();
+        for _ in 0..6 {
+            stats.count_input();
+        }
+        stats.receive(&put(Tetrimino::S, None));
+        stats.receive(&put(Tetrimino::Z, None));
+
+        assert_eq!(stats.pps(&Duration::from_secs(4)), 0.5);
+        assert_eq!(stats.kpp(), 3.);
+        assert_eq!(stats.apm(&Duration::ZERO), 0.);
+    }
+}